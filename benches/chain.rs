@@ -0,0 +1,43 @@
+//! Compares compiling a long chain once with compiling it at every step.
+//! Run it with `cargo bench --bench chain`.
+
+use magic_regexp::{Digit, Exactly, Letter, OneOrMore, Pattern, Text, Times};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LINKS: usize = 50;
+const ROUNDS: u32 = 20;
+
+/// Returns the average duration of the given closure over all rounds.
+fn measure(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let deferred = measure(|| {
+        let pattern = (0..LINKS).fold(Pattern::new(), |pattern, _| {
+            pattern
+                .and(Times(Digit, 2))
+                .and(Exactly(Text("-".to_string())))
+                .and(OneOrMore(Letter))
+        });
+        black_box(pattern.build().unwrap());
+    });
+    let eager = measure(|| {
+        let mut pattern = Pattern::new();
+        for _ in 0..LINKS {
+            pattern = pattern
+                .and(Times(Digit, 2))
+                .and(Exactly(Text("-".to_string())))
+                .and(OneOrMore(Letter));
+            // Compiles every intermediate step, like the chain did before it was deferred.
+            black_box(pattern.build().unwrap());
+        }
+    });
+    println!("compile once:       {:?}", deferred);
+    println!("compile every step: {:?}", eager);
+}
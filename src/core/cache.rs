@@ -0,0 +1,60 @@
+use crate::Result;
+use regex::Regex;
+
+/// Compiles the given regex string.
+/// With the `cache` feature, every compiled regex is kept, so compiling the same string again only clones it.
+/// The cache is never cleared, so it is only meant for a limited set of patterns, which are built repeatedly.
+#[cfg(feature = "cache")]
+pub(crate) fn compile(pattern: &str) -> Result<Regex> {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(regex) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(pattern) {
+        #[cfg(test)]
+        count_hit(pattern);
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern)?;
+    cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(pattern.to_string(), regex.clone());
+    Ok(regex)
+}
+
+/// Returns the hits of the cache for every pattern, so tests can check, that a pattern is not compiled again.
+#[cfg(all(feature = "cache", test))]
+fn hit_counts() -> &'static std::sync::Mutex<std::collections::HashMap<String, usize>> {
+    static HITS: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<String, usize>>> =
+        std::sync::OnceLock::new();
+    HITS.get_or_init(Default::default)
+}
+
+#[cfg(all(feature = "cache", test))]
+fn count_hit(pattern: &str) {
+    *hit_counts()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(pattern.to_string())
+        .or_default() += 1;
+}
+
+/// Returns how often the given pattern was taken from the cache instead of being compiled.
+#[cfg(all(feature = "cache", test))]
+pub(crate) fn hits(pattern: &str) -> usize {
+    hit_counts()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(pattern)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Compiles the given regex string.
+#[cfg(not(feature = "cache"))]
+pub(crate) fn compile(pattern: &str) -> Result<Regex> {
+    Ok(Regex::new(pattern)?)
+}
@@ -0,0 +1,66 @@
+use crate::{Error, Result};
+use std::fmt;
+
+/// The inline flags, which can be set for a statement with `Condition::with_flags`.
+/// All flags are off by default, so only the wanted ones have to be set.
+///
+/// # Example
+/// ```
+/// use magic_regexp::Flags;
+///
+/// let flags = Flags {
+///     case_insensitive: true,
+///     multiline: true,
+///     ..Flags::default()
+/// };
+/// assert_eq!(flags.to_string(), "im");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Flags {
+    /// Letters match both upper and lower case, `i`.
+    pub case_insensitive: bool,
+    /// `^` and `$` match at the start and end of every line, `m`.
+    pub multiline: bool,
+    /// `.` also matches a newline, `s`.
+    pub dot_all: bool,
+    /// Whitespace is ignored and `#` starts a comment, `x`.
+    pub ignore_whitespace: bool,
+    /// The meaning of greedy and lazy quantifiers is swapped, `U`.
+    pub swap_greed: bool,
+}
+
+impl Flags {
+    /// Checks, if the flags can be applied to the given regex string.
+    /// With `ignore_whitespace`, an unescaped whitespace in the regex would silently be dropped
+    /// and an unescaped `#` would turn the rest of the regex into a comment, so this returns an error instead.
+    pub(crate) fn validate(&self, regex: &str) -> Result<()> {
+        if !self.ignore_whitespace {
+            return Ok(());
+        }
+        let mut chars = regex.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if c.is_whitespace() || c == '#' {
+                return Err(Error::ConflictingFlags(regex.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Flags {
+    /// Writes the letters of all set flags in the order `imsxU`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        [
+            (self.case_insensitive, "i"),
+            (self.multiline, "m"),
+            (self.dot_all, "s"),
+            (self.ignore_whitespace, "x"),
+            (self.swap_greed, "U"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .try_for_each(|(_, flag)| f.write_str(flag))
+    }
+}
@@ -0,0 +1,183 @@
+use crate::core::cache::compile;
+use crate::Result;
+use regex::{Captures, Match, Regex};
+use std::collections::HashMap;
+
+/// Returns all named capture groups of the first match as a map from the group name to the captured text.
+/// Groups, which did not participate in the match, are not part of the map.
+/// Returns `None`, if the regex does not match.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, named_captures, Condition, Digit, Exactly, Text, Times};
+///
+/// let regex = create_reg_exp(
+///     Times(Digit, 4)
+///         .grouped_as("year")
+///         .and(Exactly(Text("-".to_string())))
+///         .and(Times(Digit, 2).grouped_as("month")),
+/// )
+/// .unwrap();
+/// let captures = named_captures(&regex, "2014-01").unwrap();
+/// assert_eq!(captures["year"], "2014");
+/// assert_eq!(captures["month"], "01");
+/// ```
+pub fn named_captures<'t>(re: &Regex, text: &'t str) -> Option<HashMap<String, &'t str>> {
+    let captures = re.captures(text)?;
+    Some(captures_to_map(re, &captures))
+}
+
+/// Returns the text of the named capture group in the first match, or the given default,
+/// if the group is optional and did not participate in the match.
+/// Returns `None`, if the regex does not match at all.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, named_capture_or, Condition, Digit, Exactly, OneOrMore, Text};
+///
+/// let patch = Exactly(Text(".".to_string())).and(OneOrMore(Digit).grouped_as("patch"));
+/// let regex = create_reg_exp(OneOrMore(Digit).and(patch.optionally())).unwrap();
+/// assert_eq!(named_capture_or(&regex, "1.2", "patch", "0"), Some("2"));
+/// assert_eq!(named_capture_or(&regex, "1", "patch", "0"), Some("0"));
+/// ```
+pub fn named_capture_or<'t>(
+    re: &Regex,
+    text: &'t str,
+    name: &str,
+    default: &'t str,
+) -> Option<&'t str> {
+    let captures = re.captures(text)?;
+    Some(captures.name(name).map_or(default, |m| m.as_str()))
+}
+
+/// Returns all named capture groups, which participated in the match, as a map from the group name to the captured text.
+fn captures_to_map<'t>(re: &Regex, captures: &Captures<'t>) -> HashMap<String, &'t str> {
+    re.capture_names()
+        .flatten()
+        .filter_map(|name| Some((name.to_string(), captures.name(name)?.as_str())))
+        .collect()
+}
+
+/// Returns all non-overlapping matches of the regex in the given text as owned strings.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, find_all, Digit, OneOrMore};
+///
+/// let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+/// assert_eq!(find_all(&regex, "a1b22c333"), ["1", "22", "333"]);
+/// ```
+pub fn find_all(re: &Regex, text: &str) -> Vec<String> {
+    re.find_iter(text).map(|m| m.as_str().to_string()).collect()
+}
+
+/// Returns the number of non-overlapping matches of the regex in the given text.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{count_matches, create_reg_exp, Digit, OneOrMore};
+///
+/// let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+/// assert_eq!(count_matches(&regex, "a1b22c333"), 3);
+/// ```
+pub fn count_matches(re: &Regex, text: &str) -> usize {
+    re.find_iter(text).count()
+}
+
+/// Returns the number of characters in all matches of the regex in the given text,
+/// or `None`, if the regex does not match at all.
+/// For a regex of a single-character type like `OneOrMore(Digit)`, this is the number of repetitions,
+/// which is useful for rules like "at least 3 digits".
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, match_repetition_count, Digit, OneOrMore};
+///
+/// let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+/// assert_eq!(match_repetition_count(&regex, "a1b22"), Some(3));
+/// assert_eq!(match_repetition_count(&regex, "abc"), None);
+/// ```
+pub fn match_repetition_count(re: &Regex, text: &str) -> Option<usize> {
+    let mut matches = re.find_iter(text).peekable();
+    matches.peek()?;
+    Some(matches.map(|m| m.as_str().chars().count()).sum())
+}
+
+/// Returns the number of matches of the regex in the given text, including overlapping ones.
+/// After every match, the search starts again one character after the start of that match.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{count_matches, count_overlapping, create_reg_exp, Exactly, Text};
+///
+/// let regex = create_reg_exp(Exactly(Text("aa".to_string()))).unwrap();
+/// assert_eq!(count_overlapping(&regex, "aaa"), 2);
+/// assert_eq!(count_matches(&regex, "aaa"), 1);
+/// ```
+pub fn count_overlapping(re: &Regex, text: &str) -> usize {
+    let mut count = 0;
+    let mut start = 0;
+    while let Some(m) = re.find_at(text, start) {
+        count += 1;
+        match text[m.start()..].chars().next() {
+            Some(c) => start = m.start() + c.len_utf8(),
+            None => break,
+        }
+    }
+    count
+}
+
+/// Returns the match of the whole given text, if the regex matches all of it and not only a part.
+/// The regex does not need to be anchored, it is wrapped like `create_reg_exp_anchored` does.
+/// The anchored regex is compiled with the default options on every call, unless the `cache` feature is enabled,
+/// so an error like an exceeded size limit is returned instead of `None`.
+/// To match many texts or to keep options of a `RegexBuilder`, build the anchored regex once
+/// with `create_reg_exp_anchored` and use `Regex::find` instead.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{anchored_find, create_reg_exp, Digit, OneOrMore};
+///
+/// let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+/// assert_eq!(anchored_find(&regex, "123").unwrap().unwrap().as_str(), "123");
+/// assert!(anchored_find(&regex, "123abc").unwrap().is_none());
+/// ```
+pub fn anchored_find<'t>(re: &Regex, text: &'t str) -> Result<Option<Match<'t>>> {
+    let anchored = compile(&format!(r"\A(?:{})\z", re.as_str()))?;
+    Ok(anchored.find(text))
+}
+
+/// Returns the text, in which all matches of the regex are replaced with the given replacement.
+/// The replacement can refer to capture groups like `$1` or `$name`, see `Regex::replace_all`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, replace_all_with, Digit, OneOrMore};
+///
+/// let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+/// assert_eq!(replace_all_with(&regex, "a1b22", "#"), "a#b#");
+/// ```
+pub fn replace_all_with(re: &Regex, text: &str, rep: &str) -> String {
+    re.replace_all(text, rep).into_owned()
+}
+
+/// Returns the text, in which all matches of the regex are replaced with the result of the given closure.
+/// The closure gets the named capture groups of every match like `named_captures` returns them.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, replace_all_with_captures, Letter, OneOrMore};
+///
+/// let regex = create_reg_exp(OneOrMore(Letter).grouped_as("word")).unwrap();
+/// let replaced = replace_all_with_captures(&regex, "hi there", |captures| captures["word"].to_uppercase());
+/// assert_eq!(replaced, "HI THERE");
+/// ```
+pub fn replace_all_with_captures<F>(re: &Regex, text: &str, mut rep: F) -> String
+where
+    F: FnMut(&HashMap<String, &str>) -> String,
+{
+    re.replace_all(text, |captures: &Captures| {
+        rep(&captures_to_map(re, captures))
+    })
+    .into_owned()
+}
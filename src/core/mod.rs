@@ -1,5 +1,30 @@
+pub(crate) mod cache;
+mod flags;
+mod matches;
+mod parse;
+mod pattern;
+mod patterns;
+mod posix;
+mod pretty;
+mod shortcuts;
 mod traits;
 mod r#type;
 
-pub use r#type::{not, Input, Input::*, Type, Type::*};
+pub use flags::Flags;
+pub use matches::{
+    anchored_find, count_matches, count_overlapping, find_all, match_repetition_count,
+    named_capture_or, named_captures, replace_all_with, replace_all_with_captures,
+};
+pub use pattern::Pattern;
+pub use patterns::{
+    backtick_quoted, decimal, double_quoted, host, integer, ipv4, ipv4_octet, path_segment,
+    percent_encoded, positive_integer, scheme, single_quoted,
+};
+pub use posix::PosixClass;
+pub use pretty::explain_all;
+pub use r#type::{any_of, escape, not, one_of, try_not, Input, Input::*, Type, Type::*};
+pub use shortcuts::{
+    digits, letters, one_or_more_digits, one_or_more_lazy, one_or_more_letters, repeat_text,
+    repeat_text_ci, words_between,
+};
 pub use traits::{AsRegex, Condition, Error, Result};
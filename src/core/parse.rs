@@ -0,0 +1,275 @@
+use crate::{Error, Input, PosixClass, Result, Type};
+use std::str::FromStr;
+
+/// Characters, which have a special meaning in a regex outside of a character class.
+const META_CHARS: &str = r".*+?^$()[]{}|\/";
+
+/// Returns all types without a payload, so their rendered form can be compared.
+fn simple_types() -> Vec<Type> {
+    vec![
+        Type::Digit,
+        Type::NotDigit,
+        Type::DigitNonZero,
+        Type::NotDigitNonZero,
+        Type::WordBoundary,
+        Type::NotWordBoundary,
+        Type::Word,
+        Type::NotWord,
+        Type::Words,
+        Type::WordChar,
+        Type::NotWordChar,
+        Type::LineStart,
+        Type::LineEnd,
+        Type::Char,
+        Type::AnyCharIncludingNewline,
+        Type::Whitespace,
+        Type::NotWhitespace,
+        Type::SpaceOnly,
+        Type::NotSpaceOnly,
+        Type::Letter,
+        Type::NotLetter,
+        Type::UnicodeLetter,
+        Type::NotUnicodeLetter,
+        Type::LetterLowercase,
+        Type::NotLetterLowercase,
+        Type::LetterUppercase,
+        Type::NotLetterUppercase,
+        Type::Tab,
+        Type::NotTab,
+        Type::Linefeed,
+        Type::NotLinefeed,
+        Type::CarriageReturn,
+        Type::NotCarriageReturn,
+        Type::NewlineAny,
+        Type::Null,
+        Type::NotNull,
+        Type::Bell,
+        Type::NotBell,
+        Type::FormFeed,
+        Type::NotFormFeed,
+        Type::VerticalTab,
+        Type::NotVerticalTab,
+        Type::Emoji,
+        Type::NotEmoji,
+        Type::Punctuation,
+        Type::NotPunctuation,
+        Type::HexDigit,
+        Type::NotHexDigit,
+        Type::Alphanumeric,
+        Type::NotAlphanumeric,
+    ]
+}
+
+/// Returns the character after a backslash, if it is escaped literally.
+/// An escaped letter or digit like `\d` is a class or an escape sequence, which is not understood here.
+fn escaped_char(c: Option<char>) -> Option<char> {
+    c.filter(|c| !c.is_alphanumeric())
+}
+
+/// Splits the body of a character class into its characters.
+/// The flag is set, if the character was escaped.
+fn class_atoms(body: &str) -> Option<Vec<(char, bool)>> {
+    let mut atoms = Vec::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            atoms.push((escaped_char(chars.next())?, true));
+        } else {
+            atoms.push((c, false));
+        }
+    }
+    Some(atoms)
+}
+
+/// Parses a character class like `[a-f]` or `[^a-f]` into a char range.
+fn parse_char_range(body: &str) -> Option<(char, char)> {
+    match class_atoms(body)?.as_slice() {
+        [(start, _), ('-', false), (end, _)] => Some((*start, *end)),
+        _ => None,
+    }
+}
+
+/// Removes the escaping backslashes of the body of a character class.
+/// Returns `None`, if the body is empty or contains an unescaped `[` or `]`.
+/// The flag is set, if the body contains an escaped `-`, so all characters are meant literally
+/// like in `Type::AnyOf` and `Type::AnythingBut` and not as a range like in `Type::Options`.
+fn unescape_options(body: &str) -> Option<(String, bool)> {
+    let atoms = class_atoms(body)?;
+    if atoms.is_empty() || atoms.contains(&('[', false)) || atoms.contains(&(']', false)) {
+        return None;
+    }
+    let literal = atoms.contains(&('-', true));
+    Some((atoms.into_iter().map(|(c, _)| c).collect(), literal))
+}
+
+/// Removes the escaping backslashes of the given text.
+/// Returns `None`, if the text contains an unescaped meta character or an escaped letter or digit.
+fn unescape_text(s: &str) -> Option<String> {
+    let mut text = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.push(escaped_char(chars.next())?),
+            c if META_CHARS.contains(c) => return None,
+            c => text.push(c),
+        }
+    }
+    Some(text)
+}
+
+impl FromStr for Type {
+    type Err = Error;
+
+    /// Parses the regex representation of a type back into the type.
+    /// This is best-effort and only understands the regexes, which are created by this crate.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::Type;
+    ///
+    /// assert_eq!(r"\d".parse::<Type>().unwrap(), Type::Digit);
+    /// assert_eq!("[a-f]".parse::<Type>().unwrap(), Type::CharInRange('a', 'f'));
+    /// assert!(r"\d|\w".parse::<Type>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(t) = simple_types().into_iter().find(|t| t.to_string() == s) {
+            return Ok(t);
+        }
+        if let Some(name) = s.strip_prefix("[[:").and_then(|s| s.strip_suffix(":]]")) {
+            let (negated, name) = match name.strip_prefix('^') {
+                Some(name) => (true, name),
+                None => (false, name),
+            };
+            if let Some(class) = PosixClass::all().into_iter().find(|c| c.name() == name) {
+                return Ok(if negated {
+                    Type::NotPosix(class)
+                } else {
+                    Type::Posix(class)
+                });
+            }
+        }
+        if let Some(name) = s.strip_prefix(r"\p{").and_then(|s| s.strip_suffix('}')) {
+            return Ok(Type::UnicodeCategory(name.to_string()));
+        }
+        if let Some(name) = s.strip_prefix(r"\P{").and_then(|s| s.strip_suffix('}')) {
+            return Ok(Type::NotUnicodeCategory(name.to_string()));
+        }
+        if let Some(body) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            if let Some((start, end)) = body.strip_prefix('^').and_then(parse_char_range) {
+                return Ok(Type::NotCharInRange(start, end));
+            }
+            if let Some((start, end)) = parse_char_range(body) {
+                return Ok(Type::CharInRange(start, end));
+            }
+            if let Some((chars, literal)) = body.strip_prefix('^').and_then(unescape_options) {
+                return Ok(if literal {
+                    Type::AnythingBut(chars)
+                } else {
+                    Type::NotOptions(chars)
+                });
+            }
+            if let Some((chars, literal)) = unescape_options(body) {
+                return Ok(if literal {
+                    Type::AnyOf(chars.chars().collect())
+                } else {
+                    Type::Options(chars)
+                });
+            }
+        }
+        if !s.is_empty() && !s.contains(|c| META_CHARS.contains(c)) {
+            return Ok(Type::Text(s.to_string()));
+        }
+        Err(Error::UnsupportedRegex(s.to_string()))
+    }
+}
+
+/// Splits a counted repetition like `{2,4}` from the end of the given regex.
+/// Returns the remaining regex and the minimum and maximum count, where a missing maximum means unbounded.
+fn split_repetition(s: &str) -> Option<(&str, usize, Option<usize>)> {
+    let rest = s.strip_suffix('}')?;
+    let open = rest.rfind('{')?;
+    let (rest, count) = (&rest[..open], &rest[open + 1..]);
+    if rest.ends_with('\\') {
+        return None;
+    }
+    match count.split_once(',') {
+        None => {
+            let n = count.parse().ok()?;
+            Some((rest, n, Some(n)))
+        }
+        Some((min, "")) => Some((rest, min.parse().ok()?, None)),
+        Some((min, max)) => Some((rest, min.parse().ok()?, Some(max.parse().ok()?))),
+    }
+}
+
+/// Parses the type of a quantified input, which may be wrapped in a non-capturing group like `(?:ab)`.
+/// Without a group, only a single atom is accepted, because the quantifier only applies to the last part
+/// of a text like `ab+` or a word like `\b\w+\b+`.
+fn parse_atom(s: &str) -> Result<Type> {
+    if let Some(inner) = s.strip_prefix("(?:").and_then(|s| s.strip_suffix(')')) {
+        if let Ok(t) = inner.parse() {
+            return Ok(t);
+        }
+    }
+    match s.parse()? {
+        Type::Text(text) if text.chars().count() != 1 => {
+            Err(Error::UnsupportedRegex(s.to_string()))
+        }
+        Type::Word | Type::NotWord | Type::Words => Err(Error::UnsupportedRegex(s.to_string())),
+        t => Ok(t),
+    }
+}
+
+impl FromStr for Input {
+    type Err = Error;
+
+    /// Parses the regex representation of an input back into the input.
+    /// This is best-effort and only understands the regexes, which are created by this crate.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Input, Type};
+    ///
+    /// assert_eq!(r"\d{4}".parse::<Input>().unwrap(), Input::Times(Type::Digit, 4));
+    /// assert_eq!(r"[01]+".parse::<Input>().unwrap(), Input::OneOrMore(Type::Options("01".into())));
+    /// assert!(r"(\d+)".parse::<Input>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let err = || Error::UnsupportedRegex(s.to_string());
+        let escaped = |rest: &str| rest.ends_with('\\') && !rest.ends_with(r"\\");
+
+        if let Some((rest, min, max)) = split_repetition(s) {
+            let t = parse_atom(rest)?;
+            return Ok(match (min, max) {
+                (min, None) => Input::AtLeast(t, min),
+                (min, Some(max)) if min == max => Input::Times(t, min),
+                (0, Some(max)) => Input::AtMost(t, max),
+                (min, Some(max)) => Input::Between(t, min, max),
+            });
+        }
+        if let Some(rest) = s.strip_suffix("+?").filter(|rest| !escaped(rest)) {
+            return Ok(Input::LazilyOneOrMore(parse_atom(rest)?));
+        }
+        if let Some(rest) = s.strip_suffix("??").filter(|rest| !escaped(rest)) {
+            return Ok(Input::LazilyMaybe(parse_atom(rest)?));
+        }
+        if let Some(rest) = s.strip_suffix('+').filter(|rest| !escaped(rest)) {
+            return Ok(Input::OneOrMore(parse_atom(rest)?));
+        }
+        if let Some(rest) = s.strip_suffix('?').filter(|rest| !escaped(rest)) {
+            return Ok(Input::Maybe(parse_atom(rest)?));
+        }
+        if let Some(inner) = s.strip_prefix(r"\b").and_then(|s| s.strip_suffix(r"\b")) {
+            if let Ok(t) = inner.parse::<Type>() {
+                return Ok(Input::Exactly(t));
+            }
+        }
+        if let Ok(t @ (Type::LineStart | Type::LineEnd)) = s.parse::<Type>() {
+            return Ok(Input::Exactly(t));
+        }
+        let text = unescape_text(s)
+            .filter(|text| !text.is_empty())
+            .ok_or_else(err)?;
+        Ok(Input::Exactly(Type::Text(text)))
+    }
+}
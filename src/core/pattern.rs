@@ -0,0 +1,333 @@
+use crate::core::cache::compile;
+use crate::{AsRegex, Condition, Error, Input, Result, Type};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A builder, which accumulates regex statements as a string and only compiles them once in `build`.
+/// Use this for longer patterns, where compiling every intermediate step would be wasteful.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{Digit, Input, Pattern, Text, Type};
+///
+/// let regex = Pattern::new()
+///     .push_input(Input::Times(Digit, 4))
+///     .push_input(Input::Exactly(Text("-".to_string())))
+///     .push_input(Input::Times(Digit, 2))
+///     .build()
+///     .unwrap();
+/// assert_eq!(regex.as_str(), r"\d{4}-\d{2}");
+/// assert!(regex.is_match("2014-01"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pattern {
+    pattern: String,
+    /// The annotated lines of `to_pretty_string`, one for every segment.
+    lines: Vec<String>,
+    /// The first error of a statement in the pattern, which is returned by `build`.
+    error: Option<Error>,
+}
+
+impl Pattern {
+    /// Returns an empty pattern.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pattern, which consists of the given regex string.
+    /// The regex string has no description, so it is a single segment without a comment.
+    pub(crate) fn from_string(pattern: String) -> Self {
+        Self {
+            lines: vec![pattern.clone()],
+            pattern,
+            error: None,
+        }
+    }
+
+    /// Returns the pattern, which consists of the given statement and keeps its description.
+    pub(crate) fn from_statement(statement: &impl AsRegex) -> Self {
+        Self::new().and_ref(statement)
+    }
+
+    /// Returns the pattern, which consists of a single segment with the given description.
+    pub(crate) fn described(pattern: String, description: &str) -> Self {
+        Self {
+            lines: vec![format!("{} // {}", pattern, description)],
+            pattern,
+            error: None,
+        }
+    }
+
+    /// Returns the pattern, which keeps the first error of the given statement, if it has none yet.
+    pub(crate) fn checked(mut self, statement: &impl AsRegex) -> Self {
+        if self.error.is_none() {
+            self.error = statement.validate().err();
+        }
+        self
+    }
+
+    /// Appends the given type to the pattern.
+    pub fn push_type(self, t: Type) -> Self {
+        self.and(t)
+    }
+
+    /// Appends the given input to the pattern.
+    pub fn push_input(self, input: Input) -> Self {
+        self.and(input)
+    }
+
+    /// Appends the given statement to the pattern.
+    pub fn and(self, other: impl AsRegex) -> Self {
+        self.and_ref(&other)
+    }
+
+    /// Appends the given statement to the pattern, but borrows it.
+    fn and_ref(self, other: &impl AsRegex) -> Self {
+        let mut this = self.checked(other);
+        let regex = other.to_string();
+        if has_top_level_alternation(&regex) {
+            // A compiled regex like `a|b` is wrapped, so the alternation does not swallow the chain.
+            this.pattern.push_str(&format!("(?:{})", regex));
+        } else {
+            this.pattern.push_str(&regex);
+        }
+        this.lines
+            .extend(other.to_pretty_string().lines().map(str::to_string));
+        this
+    }
+
+    /// Returns the pattern, which matches either the pattern so far or the given statement.
+    pub fn or(self, other: impl AsRegex) -> Self {
+        let other = Self::from_statement(&other);
+        let mut lines = vec!["(?:".to_string()];
+        lines.extend(indent(&self.lines));
+        lines.push("|".to_string());
+        lines.extend(indent(&other.lines));
+        lines.push(")".to_string());
+        Self {
+            pattern: format!("(?:{}|{})", self.pattern, other.pattern),
+            lines,
+            error: self.error.or(other.error),
+        }
+    }
+
+    /// Returns the pattern, which sets the pattern so far to optional.
+    pub fn optionally(self) -> Self {
+        self.wrap("(?:", ")?", "optional")
+    }
+
+    /// Returns the pattern, which defines the pattern so far as a named capture group.
+    /// Unlike `Input::grouped_as`, this can name a whole chain and the chain can continue afterwards.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Condition, Digit, Exactly, Text, Times};
+    ///
+    /// let regex = Times(Digit, 2)
+    ///     .and(Exactly(Text(":".to_string())))
+    ///     .and(Times(Digit, 2))
+    ///     .grouped_as("time")
+    ///     .and(Exactly(Text("h".to_string())))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(&regex.captures("12:30h").unwrap()["time"], "12:30");
+    /// ```
+    pub fn grouped_as(self, name: &str) -> Self {
+        self.wrap(&format!("(?P<{}>", name), ")", name)
+    }
+
+    /// Returns the pattern, which defines the pattern so far as an anonymous capture group.
+    pub fn grouped(self) -> Self {
+        self.wrap("(", ")", "group")
+    }
+
+    /// Returns the pattern between the given opening and closing regex.
+    /// The segments so far are indented between an opening and a closing line, which has the given comment.
+    pub(crate) fn wrap(self, open: &str, close: &str, comment: &str) -> Self {
+        let mut lines = vec![open.to_string()];
+        lines.extend(indent(&self.lines));
+        lines.push(annotate(close, comment));
+        Self {
+            pattern: format!("{}{}{}", open, self.pattern, close),
+            lines,
+            error: self.error,
+        }
+    }
+
+    /// Returns the pattern with the given regex before it, as a segment with the given comment.
+    pub(crate) fn prepend(mut self, regex: &str, comment: &str) -> Self {
+        self.pattern.insert_str(0, regex);
+        self.lines.insert(0, annotate(regex, comment));
+        self
+    }
+
+    /// Returns the pattern with the given regex after it, as a segment with the given comment.
+    pub(crate) fn append(mut self, regex: &str, comment: &str) -> Self {
+        self.pattern.push_str(regex);
+        self.lines.push(annotate(regex, comment));
+        self
+    }
+
+    /// Returns the pattern string, annotated with a comment for every segment, like `\d{4} // 4 digits (year)`.
+    /// Every segment is written on its own line and groups indent the segments inside of them.
+    /// This is only meant for debugging and does not change the regex, which is built.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Condition, Digit, Exactly, Text, Times};
+    ///
+    /// let pattern = Times(Digit, 4)
+    ///     .grouped_as("year")
+    ///     .and(Exactly(Text("-".to_string())))
+    ///     .and(Times(Digit, 2).grouped_as("month"));
+    /// assert_eq!(
+    ///     pattern.to_pretty_string(),
+    ///     "(?P<year>\\d{4}) // 4 digits (year)\n- // literal '-'\n(?P<month>\\d{2}) // 2 digits (month)"
+    /// );
+    /// ```
+    pub fn to_pretty_string(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the pattern string so far without compiling it.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns all capture groups of the pattern, mapped from their index to their name and their source segment.
+    /// The indices are the same, which the compiled regex uses, so index `1` is the first group.
+    /// Anonymous groups have no name. Non-capturing groups and escaped parentheses are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Condition, Digit, Letter, OneOrMore};
+    ///
+    /// let pattern = OneOrMore(Digit).grouped_as("number").and(OneOrMore(Letter).grouped());
+    /// let groups = pattern.group_map();
+    /// assert_eq!(groups[&1], (Some("number".to_string()), r"(?P<number>\d+)".to_string()));
+    /// assert_eq!(groups[&2], (None, "([a-zA-Z]+)".to_string()));
+    /// ```
+    pub fn group_map(&self) -> BTreeMap<usize, (Option<String>, String)> {
+        let mut groups = BTreeMap::new();
+        // The start of every open group and its index, if it captures.
+        let mut open: Vec<(usize, Option<usize>)> = Vec::new();
+        let mut index = 0;
+        let mut class_depth = 0;
+        let mut chars = self.pattern.char_indices().peekable();
+        while let Some((pos, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '[' => class_depth += 1,
+                ']' if class_depth > 0 => class_depth -= 1,
+                '(' if class_depth == 0 => {
+                    let rest = &self.pattern[pos + 1..];
+                    let name = group_name(rest);
+                    if !rest.starts_with('?') || name.is_some() {
+                        index += 1;
+                        groups.insert(index, (name, String::new()));
+                        open.push((pos, Some(index)));
+                    } else {
+                        open.push((pos, None));
+                    }
+                }
+                ')' if class_depth == 0 => {
+                    if let Some((start, Some(index))) = open.pop() {
+                        if let Some(group) = groups.get_mut(&index) {
+                            group.1 = self.pattern[start..=pos].to_string();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        groups
+    }
+
+    /// Compiles the pattern into a regex.
+    /// Returns the first error of a statement in the pattern, like `Error::InvalidRange`, before the regex is compiled.
+    pub fn build(&self) -> Result<Regex> {
+        self.validate()?;
+        compile(&self.pattern)
+    }
+}
+
+/// Two patterns are equal, if they consist of the same regex string.
+/// The annotations of `to_pretty_string` are not compared.
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for Pattern {}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl AsRegex for Pattern {
+    fn as_regex(&self) -> Result<Regex> {
+        self.build()
+    }
+
+    fn to_pretty_string(&self) -> String {
+        Pattern::to_pretty_string(self)
+    }
+
+    fn validate(&self) -> Result<()> {
+        match &self.error {
+            Some(e) => Err(e.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Condition for Pattern {}
+
+/// Returns the given regex with the given comment, or only the regex, if the comment is empty.
+fn annotate(regex: &str, comment: &str) -> String {
+    if comment.is_empty() {
+        return regex.to_string();
+    }
+    format!("{} // {}", regex, comment)
+}
+
+/// Returns the given lines, indented by one level.
+fn indent(lines: &[String]) -> impl Iterator<Item = String> + '_ {
+    lines.iter().map(|line| format!("  {}", line))
+}
+
+/// Returns true, if the given regex contains a `|` outside of any group and character class.
+fn has_top_level_alternation(regex: &str) -> bool {
+    let mut depth = 0;
+    let mut class_depth = 0;
+    let mut chars = regex.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => class_depth += 1,
+            ']' if class_depth > 0 => class_depth -= 1,
+            '(' if class_depth == 0 => depth += 1,
+            ')' if class_depth == 0 => depth -= 1,
+            '|' if class_depth == 0 && depth == 0 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Returns the name of a named group, if the given text follows directly after its opening parenthesis.
+fn group_name(rest: &str) -> Option<String> {
+    let rest = rest
+        .strip_prefix("?P<")
+        .or_else(|| rest.strip_prefix("?<"))?;
+    let end = rest.find('>')?;
+    Some(rest[..end].to_string())
+}
@@ -0,0 +1,193 @@
+use crate::{Condition, Input, Pattern, Type};
+
+/// Returns the regex, which matches a number between 0 and 255 without leading zeros.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{ipv4_octet, Condition};
+///
+/// let regex = ipv4_octet().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("255"));
+/// assert!(regex.is_match("0"));
+/// assert!(!regex.is_match("256"));
+/// assert!(!regex.is_match("01"));
+/// ```
+pub fn ipv4_octet() -> Pattern {
+    // 250-255
+    let high = Input::Exactly(Type::Text("25".to_string())).and(Type::CharInRange('0', '5'));
+    // 200-249
+    let two_hundreds = Input::Exactly(Type::Text("2".to_string()))
+        .and(Type::CharInRange('0', '4'))
+        .and(Type::Digit);
+    // 100-199
+    let one_hundreds =
+        Input::Exactly(Type::Text("1".to_string())).and(Input::Times(Type::Digit, 2));
+    // 0-99
+    let low = Input::Maybe(Type::CharInRange('1', '9')).and(Type::Digit);
+
+    high.or(two_hundreds).or(one_hundreds).or(low)
+}
+
+/// Returns the regex, which matches an IPv4 address in dotted-quad notation like `192.168.0.1`.
+/// Every octet has to be between 0 and 255. The address has to stand on its own,
+/// so `256.1.1.1` does not match `56.1.1.1`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::ipv4;
+///
+/// let regex = ipv4().build().unwrap();
+/// assert!(regex.is_match("192.168.0.1"));
+/// assert!(!regex.is_match("256.1.1.1"));
+/// ```
+pub fn ipv4() -> Pattern {
+    let dot = Input::Exactly(Type::Text(".".to_string()));
+    ipv4_octet()
+        .and(dot.and(ipv4_octet()).times(3))
+        .as_whole_word()
+}
+
+/// Returns the regex, which matches an integer with an optional sign like `-42` or `+7`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{integer, Condition};
+///
+/// let regex = integer().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("-42"));
+/// assert!(regex.is_match("0"));
+/// assert!(!regex.is_match("4-2"));
+/// ```
+pub fn integer() -> Pattern {
+    Input::Maybe(Type::Options("+-".to_string())).and(Input::OneOrMore(Type::Digit))
+}
+
+/// Returns the regex, which matches a decimal number with an optional sign and an optional fractional part like `+3.14`.
+/// If there is a dot, it has to be followed by at least one digit, so `3.` and `.5` are not matched as a whole.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{decimal, Condition};
+///
+/// let regex = decimal().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("+3.14"));
+/// assert!(regex.is_match("42"));
+/// assert!(!regex.is_match("3."));
+/// ```
+pub fn decimal() -> Pattern {
+    let fraction = Input::Exactly(Type::Text(".".to_string())).and(Input::OneOrMore(Type::Digit));
+    integer().and(fraction.optionally())
+}
+
+/// Returns the regex, which matches a text in the given quotes.
+/// Inside of the quotes, every character can be escaped with a backslash, including the quote itself.
+fn quoted(quote: char) -> Pattern {
+    let quote = quote.to_string();
+    let unescaped = Pattern::new().push_type(Type::AnythingBut(format!("{}\\", quote)));
+    let escaped = Input::Exactly(Type::Text("\\".to_string())).and(Type::Char);
+    let content = unescaped.or(escaped);
+    Pattern::from_string(format!("{}{}*{}", quote, content, quote))
+}
+
+/// Returns the regex, which matches a text in double quotes like `"he said \"hi\""`.
+/// An escaped quote `\"` does not end the text. This does not need backreferences,
+/// so it works with the regex crate.
+///
+/// # Example
+/// ```
+/// use magic_regexp::double_quoted;
+///
+/// let regex = double_quoted().build().unwrap();
+/// let text = r#"say "he said \"hi\"" now"#;
+/// assert_eq!(regex.find(text).unwrap().as_str(), r#""he said \"hi\"""#);
+/// ```
+pub fn double_quoted() -> Pattern {
+    quoted('"')
+}
+
+/// Returns the regex, which matches a text in single quotes like `'it\'s'`.
+/// An escaped quote `\'` does not end the text.
+pub fn single_quoted() -> Pattern {
+    quoted('\'')
+}
+
+/// Returns the regex, which matches a text in backticks like `` `a\`b` ``.
+/// An escaped backtick does not end the text.
+pub fn backtick_quoted() -> Pattern {
+    quoted('`')
+}
+
+/// Returns the regex, which matches the scheme of a URL like `https`.
+/// The scheme has to start with a letter, followed by letters, digits, `+`, `.` or `-`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{scheme, Condition};
+///
+/// let regex = scheme().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("https"));
+/// assert!(!regex.is_match("1http"));
+/// ```
+pub fn scheme() -> Pattern {
+    Pattern::new()
+        .push_type(Type::Letter)
+        .push_input(Input::AtLeast(Type::Options("a-zA-Z0-9+.-".to_string()), 0))
+}
+
+/// Returns the regex, which matches a host name like `example.com`.
+/// Every label consists of letters, digits and `-`, but must not start or end with `-`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{host, Condition};
+///
+/// let regex = host().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("example.com"));
+/// assert!(!regex.is_match("-example.com"));
+/// ```
+pub fn host() -> Pattern {
+    let inner = Input::AtLeast(Type::Options("a-zA-Z0-9-".to_string()), 0).and(Type::Alphanumeric);
+    let label = Pattern::new()
+        .push_type(Type::Alphanumeric)
+        .and(inner.optionally());
+    label.repeated_with_separator(Input::Exactly(Type::Text(".".to_string())), 1)
+}
+
+/// Returns the regex, which matches a percent-encoded byte like `%20`.
+pub fn percent_encoded() -> Pattern {
+    Input::Exactly(Type::Text("%".to_string())).and(Input::Times(Type::HexDigit, 2))
+}
+
+/// Returns the regex, which matches a single non-empty segment of a URL path like `caf%C3%A9` in `/menu/caf%C3%A9`.
+/// The `/` between the segments is not part of it.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{path_segment, Condition};
+///
+/// let regex = path_segment().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("caf%C3%A9"));
+/// assert!(!regex.is_match("a/b"));
+/// ```
+pub fn path_segment() -> Pattern {
+    let unencoded =
+        Pattern::new().push_type(Type::Options("a-zA-Z0-9._~!$&'()*+,;=:@-".to_string()));
+    Pattern::from_string(format!("{}+", unencoded.or(percent_encoded())))
+}
+
+/// Returns the regex, which matches a positive integer without leading zeros like `7` or `10`.
+/// `0` itself is not positive, so it is not matched.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{positive_integer, Condition};
+///
+/// let regex = positive_integer().at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("10"));
+/// assert!(!regex.is_match("007"));
+/// ```
+pub fn positive_integer() -> Pattern {
+    Pattern::new()
+        .push_type(Type::DigitNonZero)
+        .and(Input::OneOrMore(Type::Digit).optionally())
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// Represents a POSIX character class, which can be used with `Type::Posix`.
+/// All classes only match ASCII characters.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{PosixClass, Type};
+///
+/// assert_eq!(Type::Posix(PosixClass::Alpha).to_string(), "[[:alpha:]]");
+/// assert_eq!(Type::NotPosix(PosixClass::Alpha).to_string(), "[[:^alpha:]]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PosixClass {
+    /// Letters and digits.
+    Alnum,
+    /// Letters.
+    Alpha,
+    /// All ASCII characters.
+    Ascii,
+    /// Space and tab.
+    Blank,
+    /// Control characters.
+    Cntrl,
+    /// Digits.
+    Digit,
+    /// Visible characters, without space.
+    Graph,
+    /// Lowercase letters.
+    Lower,
+    /// Visible characters and space.
+    Print,
+    /// Punctuation characters.
+    Punct,
+    /// Whitespace characters, including newlines.
+    Space,
+    /// Uppercase letters.
+    Upper,
+    /// Letters, digits and `_`.
+    Word,
+    /// Hex digits.
+    Xdigit,
+}
+
+impl PosixClass {
+    /// Returns all POSIX classes.
+    pub fn all() -> [PosixClass; 14] {
+        [
+            PosixClass::Alnum,
+            PosixClass::Alpha,
+            PosixClass::Ascii,
+            PosixClass::Blank,
+            PosixClass::Cntrl,
+            PosixClass::Digit,
+            PosixClass::Graph,
+            PosixClass::Lower,
+            PosixClass::Print,
+            PosixClass::Punct,
+            PosixClass::Space,
+            PosixClass::Upper,
+            PosixClass::Word,
+            PosixClass::Xdigit,
+        ]
+    }
+
+    /// Returns the name of the class, which is used in `[[:name:]]`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PosixClass::Alnum => "alnum",
+            PosixClass::Alpha => "alpha",
+            PosixClass::Ascii => "ascii",
+            PosixClass::Blank => "blank",
+            PosixClass::Cntrl => "cntrl",
+            PosixClass::Digit => "digit",
+            PosixClass::Graph => "graph",
+            PosixClass::Lower => "lower",
+            PosixClass::Print => "print",
+            PosixClass::Punct => "punct",
+            PosixClass::Space => "space",
+            PosixClass::Upper => "upper",
+            PosixClass::Word => "word",
+            PosixClass::Xdigit => "xdigit",
+        }
+    }
+}
+
+impl fmt::Display for PosixClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Input, Type};
+
+impl Type {
+    /// Returns a short english description of the type.
+    /// If `plural` is set, the description is used for more than one character.
+    pub(crate) fn describe(&self, plural: bool) -> String {
+        let (singular, multiple) = match self {
+            Type::Digit => ("digit", "digits"),
+            Type::NotDigit => ("non-digit", "non-digits"),
+            Type::DigitNonZero => ("non-zero digit", "non-zero digits"),
+            Type::NotDigitNonZero => ("non-digit or zero", "non-digits or zeros"),
+            Type::WordBoundary => ("word boundary", "word boundaries"),
+            Type::NotWordBoundary => ("non-word boundary", "non-word boundaries"),
+            Type::Word => ("word", "words"),
+            Type::NotWord => ("non-word", "non-words"),
+            Type::Words => ("sequence of words", "sequences of words"),
+            Type::WordChar => ("word character", "word characters"),
+            Type::NotWordChar => ("non-word character", "non-word characters"),
+            Type::LineStart => ("line start", "line starts"),
+            Type::LineEnd => ("line end", "line ends"),
+            Type::Char | Type::AnyChar => ("character", "characters"),
+            Type::AnyCharIncludingNewline => (
+                "character including newline",
+                "characters including newlines",
+            ),
+            Type::Whitespace => ("whitespace", "whitespaces"),
+            Type::NotWhitespace => ("non-whitespace", "non-whitespaces"),
+            Type::SpaceOnly => ("space", "spaces"),
+            Type::NotSpaceOnly => ("non-space", "non-spaces"),
+            Type::Letter => ("letter", "letters"),
+            Type::NotLetter => ("non-letter", "non-letters"),
+            Type::UnicodeLetter => ("unicode letter", "unicode letters"),
+            Type::NotUnicodeLetter => ("non-unicode letter", "non-unicode letters"),
+            Type::LetterLowercase => ("lowercase letter", "lowercase letters"),
+            Type::NotLetterLowercase => ("non-lowercase letter", "non-lowercase letters"),
+            Type::LetterUppercase => ("uppercase letter", "uppercase letters"),
+            Type::NotLetterUppercase => ("non-uppercase letter", "non-uppercase letters"),
+            Type::Tab => ("tab", "tabs"),
+            Type::NotTab => ("non-tab", "non-tabs"),
+            Type::Linefeed => ("linefeed", "linefeeds"),
+            Type::NotLinefeed => ("non-linefeed", "non-linefeeds"),
+            Type::CarriageReturn => ("carriage return", "carriage returns"),
+            Type::NotCarriageReturn => ("non-carriage return", "non-carriage returns"),
+            Type::NewlineAny => ("newline", "newlines"),
+            Type::Null => ("null character", "null characters"),
+            Type::NotNull => ("non-null character", "non-null characters"),
+            Type::Bell => ("bell character", "bell characters"),
+            Type::NotBell => ("non-bell character", "non-bell characters"),
+            Type::FormFeed => ("form feed", "form feeds"),
+            Type::NotFormFeed => ("non-form feed", "non-form feeds"),
+            Type::VerticalTab => ("vertical tab", "vertical tabs"),
+            Type::NotVerticalTab => ("non-vertical tab", "non-vertical tabs"),
+            Type::Emoji => ("emoji", "emoji"),
+            Type::NotEmoji => ("non-emoji", "non-emoji"),
+            Type::Punctuation => ("punctuation character", "punctuation characters"),
+            Type::NotPunctuation => ("non-punctuation character", "non-punctuation characters"),
+            Type::HexDigit => ("hex digit", "hex digits"),
+            Type::NotHexDigit => ("non-hex digit", "non-hex digits"),
+            Type::Alphanumeric => ("alphanumeric character", "alphanumeric characters"),
+            Type::NotAlphanumeric => ("non-alphanumeric character", "non-alphanumeric characters"),
+            Type::Text(text) => return format!("literal '{}'", text),
+            Type::Options(options) => return format!("one of [{}]", options),
+            Type::NotOptions(options) => return format!("none of [{}]", options),
+            Type::AnythingBut(chars) => return format!("anything but [{}]", chars),
+            Type::AnyOf(chars) => return format!("one of [{}]", chars.iter().collect::<String>()),
+            Type::NotAnyOf(chars) => {
+                return format!("none of [{}]", chars.iter().collect::<String>())
+            }
+            Type::Posix(class) => return format!("{} character", class),
+            Type::NotPosix(class) => return format!("non-{} character", class),
+            Type::UnicodeCategory(name) => return format!("{} character", name),
+            Type::NotUnicodeCategory(name) => return format!("non-{} character", name),
+            Type::CharInRange(start, end) => {
+                return format!("character from '{}' to '{}'", start, end)
+            }
+            Type::NotCharInRange(start, end) => {
+                return format!("character not from '{}' to '{}'", start, end)
+            }
+        };
+        if plural { multiple } else { singular }.to_string()
+    }
+
+    /// Returns a short english explanation of the type, like `digit`.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::Type;
+    ///
+    /// assert_eq!(Type::HexDigit.explain(), "hex digit");
+    /// ```
+    pub fn explain(&self) -> String {
+        self.describe(false)
+    }
+}
+
+impl Input {
+    /// Returns a short english description of the input.
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            Input::OneOrMore(t) => format!("one or more {}", t.describe(true)),
+            Input::Exactly(t @ (Type::Text(_) | Type::LineStart | Type::LineEnd)) => {
+                t.describe(false)
+            }
+            // The description of a class like `one of [a-z]` already says, that one character is matched.
+            Input::Exactly(
+                t @ (Type::Options(_)
+                | Type::NotOptions(_)
+                | Type::AnythingBut(_)
+                | Type::AnyOf(_)
+                | Type::NotAnyOf(_)),
+            ) => t.describe(false),
+            Input::Exactly(t) => format!("exactly one {}", t.describe(false)),
+            Input::Maybe(t) => format!("optional {}", t.describe(false)),
+            Input::Times(t, n) => format!("{} {}", n, t.describe(*n != 1)),
+            Input::AtLeast(t, n) => format!("at least {} {}", n, t.describe(*n != 1)),
+            Input::AtMost(t, n) => format!("at most {} {}", n, t.describe(*n != 1)),
+            Input::Between(t, min, max) => {
+                format!("between {} and {} {}", min, max, t.describe(true))
+            }
+            Input::LazilyOneOrMore(t) => format!("one or more {}, lazily", t.describe(true)),
+            Input::LazilyMaybe(t) => format!("optional {}, lazily", t.describe(false)),
+            Input::TimesRange(t, min, Some(max)) if min == max => {
+                format!("{} {}", min, t.describe(*min != 1))
+            }
+            Input::TimesRange(t, min, Some(max)) => {
+                format!("between {} and {} {}", min, max, t.describe(true))
+            }
+            Input::TimesRange(t, min, None) => {
+                format!("at least {} {}", min, t.describe(*min != 1))
+            }
+        }
+    }
+
+    /// Returns a short english explanation of the input, like `one or more digits`.
+    /// Use `explain_all` for several inputs in a row.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Digit, OneOrMore};
+    ///
+    /// assert_eq!(OneOrMore(Digit).explain(), "one or more digits");
+    /// ```
+    pub fn explain(&self) -> String {
+        self.describe()
+    }
+}
+
+/// Returns a short english explanation of the given inputs, which follow each other.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{explain_all, Digit, Exactly, OneOrMore, Text};
+///
+/// let inputs = [OneOrMore(Digit), Exactly(Text("-".to_string()))];
+/// assert_eq!(explain_all(&inputs), "one or more digits followed by literal '-'");
+/// ```
+pub fn explain_all(inputs: &[Input]) -> String {
+    inputs
+        .iter()
+        .map(Input::explain)
+        .collect::<Vec<_>>()
+        .join(" followed by ")
+}
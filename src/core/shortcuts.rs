@@ -0,0 +1,119 @@
+use crate::{Condition, Input, Pattern, Type};
+
+/// Returns the input, which matches exactly `n` digits.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{digits, Condition, Exactly, Text};
+///
+/// let input = digits(4).and(Exactly(Text("-".to_string()))).and(digits(2));
+/// assert_eq!(input.to_string(), r"\d{4}-\d{2}");
+/// ```
+pub fn digits(n: usize) -> Input {
+    Input::Times(Type::Digit, n)
+}
+
+/// Returns the input, which matches exactly `n` letters.
+pub fn letters(n: usize) -> Input {
+    Input::Times(Type::Letter, n)
+}
+
+/// Returns the input, which matches one or more digits.
+pub fn one_or_more_digits() -> Input {
+    Input::OneOrMore(Type::Digit)
+}
+
+/// Returns the input, which matches one or more letters.
+pub fn one_or_more_letters() -> Input {
+    Input::OneOrMore(Type::Letter)
+}
+
+/// Returns the input, which matches the given type one or more times, but as few times as possible.
+/// This is the lazy `+?` counterpart of the greedy `Input::OneOrMore`.
+///
+/// There is no possessive variant, because the regex crate has no possessive quantifiers.
+/// It does not reject `a++` though, but reads it as a greedy repetition of `a+`, which behaves like `a+`.
+/// The regex crate matches in linear time anyway, so it does not need them to prevent catastrophic backtracking.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, one_or_more_lazy, Digit, OneOrMore};
+///
+/// let greedy = create_reg_exp(OneOrMore(Digit)).unwrap();
+/// let lazy = create_reg_exp(one_or_more_lazy(Digit)).unwrap();
+/// assert_eq!(greedy.find("123").unwrap().as_str(), "123");
+/// assert_eq!(lazy.find("123").unwrap().as_str(), "1");
+/// ```
+pub fn one_or_more_lazy(t: Type) -> Input {
+    Input::LazilyOneOrMore(t)
+}
+
+/// Returns the regex, which matches the given text exactly `n` times in a row.
+/// The text is escaped and repeated as a whole, so `repeat_text("ab", 2)` matches `abab`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::repeat_text;
+///
+/// let regex = repeat_text("-", 6);
+/// assert_eq!(regex.as_str(), "(?:-){6}");
+///
+/// let regex = regex.build().unwrap();
+/// assert!(regex.is_match("------"));
+/// assert!(!regex.is_match("-----"));
+/// ```
+pub fn repeat_text(text: &str, n: usize) -> Pattern {
+    Input::Exactly(Type::Text(text.to_string())).times(n)
+}
+
+/// Returns the regex, which matches the given text exactly `n` times in a row, ignoring the case.
+/// Only the repeated text is case-insensitive `(?i:...)`, so statements around it still match case-sensitively.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{repeat_text_ci, Condition};
+///
+/// let regex = repeat_text_ci("ab", 2).build().unwrap();
+/// assert_eq!(regex.as_str(), "(?i:(?:ab){2})");
+/// assert!(regex.is_match("ABab"));
+/// ```
+pub fn repeat_text_ci(text: &str, n: usize) -> Pattern {
+    repeat_text(text, n).case_insensitive()
+}
+
+/// Returns the regex, which matches between `min` and `max` words, separated by whitespace.
+/// Whitespace before the first and after the last word is not part of the match,
+/// so anchor the regex with `at_start` and `at_end` only for trimmed text.
+/// If `max` is `0`, the regex only matches the empty text.
+/// If `min` is greater than `max`, building the regex returns `Error::InvalidRange` like `Input::Between` does.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{words_between, Condition};
+///
+/// let regex = words_between(2, 3).at_start().at_end().build().unwrap();
+/// assert!(regex.is_match("the quick fox"));
+/// assert!(!regex.is_match("fox"));
+/// ```
+pub fn words_between(min: usize, max: usize) -> Pattern {
+    let word = Type::Word.to_string();
+    if min > max {
+        // The range is checked like `Input::Between`, so building the pattern returns `Error::InvalidRange`.
+        return Pattern::new().checked(&Input::Between(Type::Word, min, max));
+    }
+    if max == 0 {
+        return Pattern::from_string(String::new());
+    }
+    let separated_word = Input::OneOrMore(Type::Whitespace).and(Type::Word);
+    let words = Pattern::from_string(format!(
+        "{}(?:{}){{{},{}}}",
+        word,
+        separated_word,
+        min.saturating_sub(1),
+        max.saturating_sub(1)
+    ));
+    if min == 0 {
+        return words.optionally();
+    }
+    words
+}
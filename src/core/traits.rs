@@ -1,12 +1,28 @@
+use crate::core::cache::compile;
+use crate::{escape, Flags, Pattern};
 use regex::Regex;
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 /// An error, which can occur while using this crate.
 /// Mostly used to wrap errors from the Regex crate.
 pub enum Error {
     #[error("An regex error occurred")]
     RegexError(#[from] regex::Error),
+    #[error("Invalid range, min {0} is greater than max {1}")]
+    InvalidRange(usize, usize),
+    #[error("Invalid unicode category, the name must not be empty")]
+    EmptyUnicodeCategory,
+    #[error("Invalid char range, start {0:?} is greater than end {1:?}")]
+    InvalidCharRange(char, char),
+    #[error("Unsupported regex, which cannot be parsed: {0}")]
+    UnsupportedRegex(String),
+    #[error("Invalid group name: {0}")]
+    InvalidGroupName(String),
+    #[error("The flags conflict with the regex, which contains unescaped whitespace or `#`: {0}")]
+    ConflictingFlags(String),
+    #[error("The type cannot be negated: {0:?}")]
+    NotNegatable(crate::Type),
 }
 
 /// A type, which is used to return results from this crate.
@@ -18,26 +34,311 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub trait AsRegex: ToString {
     /// Returns the regex, which represents the wanted statement.
     fn as_regex(&self) -> Result<Regex> {
-        let regex = Regex::new(&self.to_string())?;
-        Ok(regex)
+        self.validate()?;
+        compile(&self.to_string())
+    }
+
+    /// Checks the statement without compiling it.
+    /// Returns an error, if the statement would otherwise result in an unexpected regex, like a range with `min` greater than `max`.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{AsRegex, Between, Digit, Error};
+    ///
+    /// assert!(matches!(Between(Digit, 4, 2).validate(), Err(Error::InvalidRange(4, 2))));
+    /// ```
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Returns the regex for byte slices, which represents the wanted statement.
+    /// It uses the same regex string as `as_regex`, so it can search text, which is not valid UTF-8.
+    /// Unicode is still enabled, so `Type::Char` and unicode types like `Type::UnicodeLetter`
+    /// only match valid UTF-8 encoded characters and never a single invalid byte.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{AsRegex, Digit, OneOrMore};
+    ///
+    /// let regex = OneOrMore(Digit).as_bytes_regex().unwrap();
+    /// assert_eq!(regex.find(b"\xFFid=42").unwrap().as_bytes(), b"42");
+    /// ```
+    fn as_bytes_regex(&self) -> Result<regex::bytes::Regex> {
+        self.validate()?;
+        Ok(regex::bytes::Regex::new(&self.to_string())?)
+    }
+
+    /// Returns the raw regex string of the statement without compiling it.
+    /// Use this to embed the statement in a larger regex or to pass it to another tool.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{AsRegex, Digit, Times};
+    ///
+    /// let pattern = format!("^{}$", Times(Digit, 4).to_regex_string());
+    /// assert_eq!(pattern, r"^\d{4}$");
+    /// ```
+    fn to_regex_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the regex string of the statement, annotated with a comment for every segment, like `\d{4} // 4 digits`.
+    /// A `Pattern` writes every segment of a composed statement on its own line.
+    /// This is only meant for debugging and does not change the regex, which is built.
+    /// Without a description, this is the same as `to_regex_string`.
+    fn to_pretty_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the number of capture groups of the statement without compiling it.
+    /// Unlike `Regex::captures_len`, the implicit group of the whole match is not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{AsRegex, Condition, Digit, OneOrMore, Times};
+    ///
+    /// let pattern = Times(Digit, 4).grouped_as("year").and(OneOrMore(Digit).non_capturing());
+    /// assert_eq!(pattern.group_count(), 1);
+    /// ```
+    fn group_count(&self) -> usize {
+        Pattern::from_string(self.to_string()).group_map().len()
+    }
+
+    /// Returns true, if the statement matches the whole given text and not only a part of it.
+    /// The statement is compiled and anchored like `create_reg_exp_anchored` does.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{AsRegex, Digit, OneOrMore};
+    ///
+    /// assert!(OneOrMore(Digit).matches_fully("123").unwrap());
+    /// assert!(!OneOrMore(Digit).matches_fully("123 ").unwrap());
+    /// ```
+    fn matches_fully(&self, text: &str) -> Result<bool> {
+        self.validate()?;
+        Ok(compile(&format!(r"\A(?:{})\z", self.to_string()))?.is_match(text))
     }
 }
 
 /// A trait, which allows to chain regex statements with conditions.
 /// Import this, if you want to use the `and`, `or` and `optionally` methods and chain statements.
+///
+/// All methods return a `Pattern`, which is only compiled once by `create_reg_exp` or `Pattern::build`.
+/// So a fragment, which is only valid in combination with the next statement, does not panic.
+///
+/// Look-around assertions like `(?=...)` or `(?<=...)` are not supported by the regex crate,
+/// so there are no `before` or `after` methods. Use a capture group around the wanted part instead.
 pub trait Condition: AsRegex + Sized {
-    /// Returns the regex, which chains the two given statements with an `and` condition.
-    fn and(self, other: impl AsRegex) -> Regex {
-        Regex::new(&format!("{}{}", self.to_string(), other.to_string()))
-            .expect("Invalid regex (and)")
-    }
-    /// Returns the regex, which chains the two given statements with an `or` condition.
-    fn or(self, other: impl AsRegex) -> Regex {
-        Regex::new(&format!("{}|{}", self.to_string(), other.to_string()))
-            .expect("Invalid regex (or)")
-    }
-    /// Returns the regex, which sets the given statement to optional.
-    fn optionally(self) -> Regex {
-        Regex::new(&format!("(?:{})?", self.to_string())).expect("Invalid regex (optionally)")
+    /// Returns the pattern, which chains the two given statements with an `and` condition.
+    /// A side with a top-level alternation like `a|b` of a compiled regex is wrapped in a non-capturing group.
+    fn and(self, other: impl AsRegex) -> Pattern {
+        Pattern::from_statement(&self).and(other)
+    }
+    /// Returns the pattern, which chains the two given statements with an `or` condition.
+    /// The alternation is wrapped in a non-capturing group, so chaining further statements does not change its precedence.
+    fn or(self, other: impl AsRegex) -> Pattern {
+        Pattern::from_statement(&self).or(other)
+    }
+    /// Returns the pattern, which chains the two given statements like `and`.
+    /// This is only an alias, which reads better in some chains like `OneOrMore(Digit).then(Exactly(Text("px")))`.
+    fn then(self, other: impl AsRegex) -> Pattern {
+        self.and(other)
+    }
+    /// Returns the pattern like `and`, but borrows both statements, so they can be reused in further compositions.
+    fn and_ref(&self, other: &impl AsRegex) -> Pattern {
+        Pattern::from_statement(self).and(Pattern::from_statement(other))
+    }
+    /// Returns the pattern like `or`, but borrows both statements, so they can be reused in further compositions.
+    fn or_ref(&self, other: &impl AsRegex) -> Pattern {
+        Pattern::from_statement(self).or(Pattern::from_statement(other))
+    }
+    /// Returns the pattern, which sets the given statement to optional.
+    fn optionally(self) -> Pattern {
+        Pattern::from_statement(&self).optionally()
+    }
+    /// Returns the regex like `and`, but compiles it right away, so an invalid statement returns an error.
+    fn try_and(self, other: impl AsRegex) -> Result<Regex> {
+        self.and(other).build()
+    }
+    /// Returns the regex like `or`, but compiles it right away, so an invalid statement returns an error.
+    fn try_or(self, other: impl AsRegex) -> Result<Regex> {
+        self.or(other).build()
+    }
+    /// Returns the regex like `optionally`, but compiles it right away, so an invalid statement returns an error.
+    fn try_optionally(self) -> Result<Regex> {
+        self.optionally().build()
+    }
+    /// Returns the pattern, which repeats the whole given statement exactly `n` times.
+    fn times(self, n: usize) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?:", &format!("){{{}}}", n), &format!("{} times", n))
+    }
+    /// Returns the pattern, which repeats the whole given statement between `min` and `max` times, but as few times as possible.
+    /// The statement is grouped, so the lazy quantifier `{min,max}?` applies to all of it.
+    fn repeat_range_lazy(self, min: usize, max: usize) -> Pattern {
+        Pattern::from_statement(&self).wrap(
+            "(?:",
+            &format!("){{{},{}}}?", min, max),
+            &format!("between {} and {} times, lazily", min, max),
+        )
+    }
+    /// Returns the pattern, which anchors the given statement to the start of a line.
+    /// Without multiline mode, this is the start of the whole text.
+    /// Calling it more than once does not add another anchor.
+    fn at_line_start(self) -> Pattern {
+        let pattern = Pattern::from_statement(&self);
+        if pattern.as_str().starts_with('^') {
+            return pattern;
+        }
+        pattern.prepend("^", "line start")
+    }
+    /// Returns the pattern, which anchors the given statement to the end of a line.
+    /// Without multiline mode, this is the end of the whole text.
+    /// Calling it more than once does not add another anchor.
+    fn at_line_end(self) -> Pattern {
+        let pattern = Pattern::from_statement(&self);
+        if pattern.as_str().ends_with('$') && !pattern.as_str().ends_with(r"\$") {
+            return pattern;
+        }
+        pattern.append("$", "line end")
+    }
+    /// Returns the pattern, which anchors the given statement to the start of the whole text.
+    /// Unlike `at_line_start`, this is not affected by multiline mode.
+    fn at_start(self) -> Pattern {
+        let pattern = Pattern::from_statement(&self);
+        if pattern.as_str().starts_with(r"\A") {
+            return pattern;
+        }
+        pattern.prepend(r"\A", "start of text")
+    }
+    /// Returns the pattern, which anchors the given statement to the end of the whole text.
+    /// Unlike `at_line_end`, this is not affected by multiline mode.
+    fn at_end(self) -> Pattern {
+        let pattern = Pattern::from_statement(&self);
+        if pattern.as_str().ends_with(r"\z") && !pattern.as_str().ends_with(r"\\z") {
+            return pattern;
+        }
+        pattern.append(r"\z", "end of text")
+    }
+    /// Returns the pattern, which matches the given statement case-insensitively.
+    fn case_insensitive(self) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?i:", ")", "case-insensitive")
+    }
+    /// Returns the pattern, in which `^` and `$` of the given statement match at the start and end of every line.
+    fn multiline(self) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?m:", ")", "multiline")
+    }
+    /// Returns the pattern, in which `.` of the given statement also matches a newline.
+    /// This does not change the behaviour of `^` and `$`, see `multiline` for that.
+    fn dot_all(self) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?s:", ")", "dot matches newline")
+    }
+    /// Returns the pattern, which only matches the given statement as a whole word.
+    /// Note that `\b` is a boundary between a word and a non-word character,
+    /// so a statement which starts or ends with a non-word character like `-` needs a word character around it instead.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_whole_word(self) -> Pattern {
+        Pattern::from_statement(&self).wrap(r"\b(?:", r")\b", "whole word")
+    }
+    /// Returns the pattern, which appends a word boundary `\b` to the given statement.
+    /// Use this to assert a boundary between two chained statements, like `a.then_boundary().and(b)`.
+    fn then_boundary(self) -> Pattern {
+        Pattern::from_statement(&self).append(r"\b", "word boundary")
+    }
+    /// Returns the pattern, which only matches the given statement at the start of a word.
+    /// The statement is grouped, so the boundary applies to all of it and not only to its first alternative.
+    fn starting_word(self) -> Pattern {
+        Pattern::from_statement(&self).wrap(r"\b(?:", ")", "start of a word")
+    }
+    /// Returns the pattern, which only matches the given statement at the end of a word.
+    /// The statement is grouped, so the boundary applies to all of it and not only to its last alternative.
+    fn ending_word(self) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?:", r")\b", "end of a word")
+    }
+    /// Returns the pattern, which matches the given statement at least `min` times, separated by `sep`.
+    /// For example, digits separated by `,` match `1,2,3`. The separator is grouped without capturing.
+    /// With a `min` of `0`, the whole list is optional.
+    fn repeated_with_separator(self, sep: impl AsRegex, min: usize) -> Pattern {
+        let item = self.to_string();
+        let list = format!(
+            "(?:{item})(?:{}(?:{item})){{{},}}",
+            sep.to_string(),
+            min.saturating_sub(1),
+        );
+        let pattern = if min == 0 {
+            Pattern::from_string(format!("(?:{})?", list))
+        } else {
+            Pattern::from_string(list)
+        };
+        pattern.checked(&self).checked(&sep)
+    }
+    /// Returns the pattern, which sets the whole given statement to optional, like `Input::Maybe` does for a single type.
+    /// This is the same as `optionally` and does not create a capture group.
+    fn maybe(self) -> Pattern {
+        self.optionally()
+    }
+    /// Returns the pattern, which matches either the given statement or nothing, written as `(?:...|)`.
+    /// This is the same as `optionally`, but for users who think in alternations.
+    /// The given statement is tried first. The regex crate matches in linear time, so this cannot backtrack catastrophically.
+    fn or_empty(self) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?:", "|)", "or empty")
+    }
+    /// Returns the pattern, which applies all given flags to the statement in a single inline group like `(?im:...)`.
+    /// Returns an error, if `ignore_whitespace` is set and the statement contains unescaped whitespace or `#`,
+    /// because whitespace would be dropped from the regex and `#` would start a comment.
+    fn with_flags(self, flags: Flags) -> Result<Pattern> {
+        flags.validate(&self.to_string())?;
+        Ok(Pattern::from_statement(&self).wrap(
+            &format!("(?{}:", flags),
+            ")",
+            &format!("flags {}", flags),
+        ))
+    }
+    /// Returns the pattern, which matches the given statement at least once, separated by `sep`,
+    /// with an optional trailing separator like `1,2,3,`. A leading separator is not allowed.
+    fn separated_list_trailing(self, sep: impl AsRegex) -> Pattern {
+        let item = self.to_string();
+        let regex = sep.to_string();
+        Pattern::from_string(format!("(?:{item})(?:{regex}(?:{item}))*(?:{regex})?"))
+            .checked(&self)
+            .checked(&sep)
+    }
+    /// Returns the pattern, which asserts a word boundary `\b` only before the given statement.
+    /// Unlike the `\b...\b` around `Input::Exactly`, the end of the statement is not bounded.
+    /// This is the same as `starting_word`.
+    fn boundary_before(self) -> Pattern {
+        self.starting_word()
+    }
+    /// Returns the pattern, which asserts a word boundary `\b` only after the given statement.
+    /// Unlike the `\b...\b` around `Input::Exactly`, the start of the statement is not bounded.
+    /// This is the same as `ending_word`.
+    fn boundary_after(self) -> Pattern {
+        self.ending_word()
+    }
+    /// Returns the pattern, which matches the given literal text right before the statement.
+    /// The text is escaped like `Input::Exactly(Type::Text)` does.
+    fn prefix(self, text: &str) -> Pattern {
+        Pattern::from_statement(&self).prepend(&escape(text), &format!("literal '{}'", text))
+    }
+    /// Returns the pattern, which matches the given literal text right after the statement.
+    /// The text is escaped like `Input::Exactly(Type::Text)` does.
+    fn suffix(self, text: &str) -> Pattern {
+        Pattern::from_statement(&self).append(&escape(text), &format!("literal '{}'", text))
+    }
+    /// Returns the pattern, which matches the given statements one after another.
+    /// Both sides are always wrapped in a non-capturing group, so a top-level alternation like `a|b`
+    /// of an already compiled regex stays on its side. `and` only wraps a side, which contains such an alternation. The capture groups of both sides are kept in order,
+    /// and no anchors are added or removed.
+    fn followed_by(self, other: impl AsRegex) -> Pattern {
+        let other = Pattern::from_statement(&other).wrap("(?:", ")", "");
+        Pattern::from_statement(&self)
+            .wrap("(?:", ")", "")
+            .and(other)
+    }
+    /// Returns the pattern, in which the given statement only uses ASCII classes `(?-u:...)`.
+    /// So `\d` only matches `0` to `9` and `\w` only `[0-9A-Za-z_]`, instead of all unicode digits and word characters.
+    /// Note that unicode types like `Type::UnicodeLetter` and types, which could match a single byte of invalid UTF-8
+    /// like `Type::Char` or `Type::NotDigit`, cannot be compiled in this mode.
+    fn ascii_only(self) -> Pattern {
+        Pattern::from_statement(&self).wrap("(?-u:", ")", "ASCII only")
     }
 }
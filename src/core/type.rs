@@ -1,31 +1,74 @@
-use crate::{AsRegex, Condition, Result};
+use crate::core::cache::compile;
+use crate::{AsRegex, Condition, Error, Pattern, PosixClass, Result};
 use regex::Regex;
+use std::fmt;
+use std::ops::{Add, BitOr};
 
 /// Represents a regex type. This enum is used to create the smallest regex statement.
 /// For example, `Type::Digit` will create the regex `\d`.
 ///
+/// Note that `Type::Letter` only matches the ASCII letters `[a-zA-Z]`, so a name like `José` is not matched as a whole.
+/// Use `Type::UnicodeLetter` for that.
+///
 /// # Examples
 /// ```
 /// use magic_regexp::{OneOrMore, Type::Digit};
 ///
 /// let input = OneOrMore(Digit);
-/// assert_eq!(input.to_string(), r"(\d+)"); // Note that the regex is wrapped in parentheses.
+/// assert_eq!(input.to_string(), r"\d+");
 /// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Type {
     Digit,
     NotDigit,
+    /// Matches a digit from `1` to `9`, which is useful for the first digit of a number without leading zeros.
+    DigitNonZero,
+    NotDigitNonZero,
     WordBoundary,
     NotWordBoundary,
     Word,
+    /// Matches a run of characters between two words, like the space in `John Doe`.
+    NotWord,
+    /// Matches several words, which are separated by whitespace, like `John Doe`.
+    Words,
     WordChar,
     NotWordChar,
     Text(String),
+    /// Matches one of the given characters. Ranges like `a-z` are allowed,
+    /// all other characters with a special meaning in a character class are escaped.
     Options(String),
+    NotOptions(String),
+    /// Matches any character except the given ones. All characters are taken literally, including `-`.
+    AnythingBut(String),
+    /// Matches one of the given characters. Unlike `Options`, all characters are taken literally,
+    /// so `-`, `]` and `^` never build a range or negate the class.
+    AnyOf(Vec<char>),
+    NotAnyOf(Vec<char>),
+    /// Matches the start of a line `^`. Without multiline mode, this is the start of the whole text.
+    /// `Input::Exactly` does not wrap it in word boundaries.
+    LineStart,
+    /// Matches the end of a line `$`. Without multiline mode, this is the end of the whole text.
+    /// `Input::Exactly` does not wrap it in word boundaries.
+    LineEnd,
+    /// Matches any character except a newline, unless `Condition::dot_all` is used.
     Char,
+    /// The same as `Char`, with a name which makes clear that a newline is not matched.
+    AnyChar,
+    /// Matches any character including a newline, without the need for `Condition::dot_all`.
+    AnyCharIncludingNewline,
     Whitespace,
     NotWhitespace,
+    /// Matches only the space character, unlike `Whitespace`, which also matches tabs and newlines.
+    /// It is written as `\x20`, so it is not dropped by the `ignore_whitespace` flag.
+    SpaceOnly,
+    NotSpaceOnly,
+    /// Matches an ASCII letter `[a-zA-Z]` only, so accented letters like `é` are not matched.
+    /// Use `UnicodeLetter` for names and other text, which is not limited to ASCII.
     Letter,
     NotLetter,
+    /// Matches any unicode letter `\p{L}`, including accented letters like `é`.
+    UnicodeLetter,
+    NotUnicodeLetter,
     LetterLowercase,
     NotLetterLowercase,
     LetterUppercase,
@@ -36,70 +79,234 @@ pub enum Type {
     NotLinefeed,
     CarriageReturn,
     NotCarriageReturn,
+    /// Matches a newline of any platform, `\r\n`, `\r` or `\n`.
+    /// It cannot be negated, because `\r\n` consists of two characters.
+    NewlineAny,
+    /// Matches the null character. The regex crate does not support `\0`, so this is `\x00`.
+    Null,
+    NotNull,
+    Bell,
+    NotBell,
+    FormFeed,
+    NotFormFeed,
+    VerticalTab,
+    NotVerticalTab,
+    HexDigit,
+    NotHexDigit,
+    /// Matches an ASCII punctuation character like `!` or `,`, using the POSIX class `[[:punct:]]`.
+    Punctuation,
+    NotPunctuation,
+    /// Matches a character of the given POSIX class like `[[:alpha:]]`.
+    Posix(PosixClass),
+    NotPosix(PosixClass),
+    /// Matches ASCII letters and digits. Unlike `WordChar`, this does not match `_`.
+    Alphanumeric,
+    NotAlphanumeric,
+    /// Matches a single emoji code point like `😀`. This uses `\p{Extended_Pictographic}`,
+    /// because `\p{Emoji}` also matches digits, `#` and `*`.
+    /// Emoji, which consist of several code points like flags or `👍🏽`, are not matched as a whole.
+    Emoji,
+    NotEmoji,
+    /// Matches a character of the given unicode category or script, like `Greek` or `L`.
+    UnicodeCategory(String),
+    NotUnicodeCategory(String),
+    /// Matches a character between the two given characters, both inclusive.
+    CharInRange(char, char),
+    NotCharInRange(char, char),
 }
 
-impl AsRegex for Type {}
-impl ToString for Type {
-    fn to_string(&self) -> String {
-        let txt;
+impl AsRegex for Type {
+    fn to_pretty_string(&self) -> String {
+        format!("{} // {}", self, self.describe(false))
+    }
+
+    /// Checks the payload of the type, which would otherwise result in an unexpected regex.
+    fn validate(&self) -> Result<()> {
         match self {
-            Type::Digit => r"\d",
-            Type::NotDigit => r"\D",
-            Type::WordBoundary => r"\b",
-            Type::NotWordBoundary => r"\B",
-            Type::Word => r"\b\w+\b",
-            Type::WordChar => r"\w",
-            Type::NotWordChar => r"\W",
-            Type::Char => r".",
-            Type::Text(text) => text,
-            Type::Whitespace => r"\s",
-            Type::NotWhitespace => r"\S",
-            Type::Letter => r"[a-zA-Z]",
-            Type::NotLetter => r"[^a-zA-Z]",
-            Type::LetterLowercase => r"[a-z]",
-            Type::NotLetterLowercase => r"[^a-z]",
-            Type::LetterUppercase => r"[A-Z]",
-            Type::NotLetterUppercase => r"[^A-Z]",
-            Type::Tab => r"\t",
-            Type::NotTab => r"^\t",
-            Type::Linefeed => r"\n",
-            Type::NotLinefeed => r"^\n",
-            Type::CarriageReturn => r"\r",
-            Type::NotCarriageReturn => r"^\r",
-            Type::Options(options) => {
-                txt = format!("[{}]", options);
-                txt.as_str()
+            Type::UnicodeCategory(name) | Type::NotUnicodeCategory(name) if name.is_empty() => {
+                Err(Error::EmptyUnicodeCategory)
+            }
+            Type::CharInRange(start, end) | Type::NotCharInRange(start, end) if start > end => {
+                Err(Error::InvalidCharRange(*start, *end))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Digit => f.write_str(r"\d"),
+            Type::NotDigit => f.write_str(r"\D"),
+            Type::DigitNonZero => f.write_str(r"[1-9]"),
+            Type::NotDigitNonZero => f.write_str(r"[^1-9]"),
+            Type::WordBoundary => f.write_str(r"\b"),
+            Type::NotWordBoundary => f.write_str(r"\B"),
+            Type::Word => f.write_str(r"\b\w+\b"),
+            Type::NotWord => f.write_str(r"\W+"),
+            Type::Words => f.write_str(r"\b\w+(?:\s+\w+)*\b"),
+            Type::WordChar => f.write_str(r"\w"),
+            Type::NotWordChar => f.write_str(r"\W"),
+            Type::LineStart => f.write_str(r"^"),
+            Type::LineEnd => f.write_str(r"$"),
+            Type::Char | Type::AnyChar => f.write_str(r"."),
+            Type::AnyCharIncludingNewline => f.write_str(r"[\s\S]"),
+            Type::Whitespace => f.write_str(r"\s"),
+            Type::NotWhitespace => f.write_str(r"\S"),
+            Type::SpaceOnly => f.write_str(r"\x20"),
+            Type::NotSpaceOnly => f.write_str(r"[^\x20]"),
+            Type::Letter => f.write_str(r"[a-zA-Z]"),
+            Type::NotLetter => f.write_str(r"[^a-zA-Z]"),
+            Type::UnicodeLetter => f.write_str(r"\p{L}"),
+            Type::NotUnicodeLetter => f.write_str(r"\P{L}"),
+            Type::LetterLowercase => f.write_str(r"[a-z]"),
+            Type::NotLetterLowercase => f.write_str(r"[^a-z]"),
+            Type::LetterUppercase => f.write_str(r"[A-Z]"),
+            Type::NotLetterUppercase => f.write_str(r"[^A-Z]"),
+            Type::Tab => f.write_str(r"\t"),
+            Type::NotTab => f.write_str(r"[^\t]"),
+            Type::Linefeed => f.write_str(r"\n"),
+            Type::NotLinefeed => f.write_str(r"[^\n]"),
+            Type::CarriageReturn => f.write_str(r"\r"),
+            Type::NotCarriageReturn => f.write_str(r"[^\r]"),
+            Type::NewlineAny => f.write_str(r"(?:\r\n|\r|\n)"),
+            Type::Null => f.write_str(r"\x00"),
+            Type::NotNull => f.write_str(r"[^\x00]"),
+            Type::Emoji => f.write_str(r"\p{Extended_Pictographic}"),
+            Type::NotEmoji => f.write_str(r"\P{Extended_Pictographic}"),
+            Type::Bell => f.write_str(r"\a"),
+            Type::NotBell => f.write_str(r"[^\a]"),
+            Type::FormFeed => f.write_str(r"\f"),
+            Type::NotFormFeed => f.write_str(r"[^\f]"),
+            Type::VerticalTab => f.write_str(r"\v"),
+            Type::NotVerticalTab => f.write_str(r"[^\v]"),
+            Type::Punctuation => f.write_str(r"[[:punct:]]"),
+            Type::NotPunctuation => f.write_str(r"[^[:punct:]]"),
+            Type::HexDigit => f.write_str(r"[0-9a-fA-F]"),
+            Type::NotHexDigit => f.write_str(r"[^0-9a-fA-F]"),
+            Type::Alphanumeric => f.write_str(r"[a-zA-Z0-9]"),
+            Type::NotAlphanumeric => f.write_str(r"[^a-zA-Z0-9]"),
+            Type::Text(text) => f.write_str(text),
+            Type::Options(options) => write!(f, "[{}]", escape_options(options)),
+            Type::NotOptions(options) => write!(f, "[^{}]", escape_options(options)),
+            Type::AnythingBut(chars) => {
+                f.write_str("[^")?;
+                chars
+                    .chars()
+                    .try_for_each(|c| f.write_str(&escape_class_char(c)))?;
+                f.write_str("]")
+            }
+            Type::AnyOf(chars) => {
+                f.write_str("[")?;
+                chars
+                    .iter()
+                    .try_for_each(|c| f.write_str(&escape_class_char(*c)))?;
+                f.write_str("]")
             }
+            Type::NotAnyOf(chars) => {
+                f.write_str("[^")?;
+                chars
+                    .iter()
+                    .try_for_each(|c| f.write_str(&escape_class_char(*c)))?;
+                f.write_str("]")
+            }
+            Type::Posix(class) => write!(f, "[[:{}:]]", class),
+            Type::NotPosix(class) => write!(f, "[[:^{}:]]", class),
+            Type::UnicodeCategory(name) => write!(f, r"\p{{{}}}", name),
+            Type::NotUnicodeCategory(name) => write!(f, r"\P{{{}}}", name),
+            Type::CharInRange(start, end) => write!(
+                f,
+                "[{}-{}]",
+                escape_class_char(*start),
+                escape_class_char(*end)
+            ),
+            Type::NotCharInRange(start, end) => write!(
+                f,
+                "[^{}-{}]",
+                escape_class_char(*start),
+                escape_class_char(*end)
+            ),
         }
-        .to_string()
     }
 }
 
+/// Escapes a character, which has a special meaning inside of a character class.
+fn escape_class_char(c: char) -> String {
+    match c {
+        '[' | ']' | '\\' | '^' | '-' | '&' | '~' => format!(r"\{}", c),
+        _ => c.to_string(),
+    }
+}
+
+/// Escapes the characters of `Type::Options`, which would break or negate the character class.
+/// A `-` is kept as it is, so ranges like `a-z` still work.
+fn escape_options(options: &str) -> String {
+    options
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '^' if i == 0 => r"\^".to_string(),
+            '-' => c.to_string(),
+            _ => escape_class_char(c),
+        })
+        .collect()
+}
+
 /// Returns the opposite of the given type.
 /// For example, `Type::Digit` will return `Type::NotDigit`.
-/// Returns the same type if it is not a type that can be negated.
+/// `Type::Char`, `Type::AnyChar`, `Type::AnyCharIncludingNewline`, `Type::NewlineAny`, `Type::Words`
+/// and the anchors `Type::LineStart` and `Type::LineEnd` are returned unchanged, use `Type::AnythingBut` to exclude specific characters.
+/// `Type::AnythingBut` itself is returned unchanged, use `Type::Options` or `Type::AnyOf` for the opposite.
 ///
-/// Panics, if the given type is `Type::Options` and the given string is empty.
+/// # Panics
+/// Panics for `Type::Text`, see `try_not` for a version, which returns an error instead.
 ///
 /// # Examples
 /// ```
 /// use magic_regexp::{OneOrMore, not, Options};
 ///
 /// let input = OneOrMore(not(not(Options("01".to_string()))));
-/// assert_eq!(input.to_string(), r"([01]+)");
+/// assert_eq!(input.to_string(), r"[01]+");
 /// ```
 pub fn not(t: Type) -> Type {
-    match t {
+    match try_not(t) {
+        Ok(t) => t,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Returns the opposite of the given type like `not`, but returns an error for `Type::Text`.
+/// A text has no single-character opposite and the regex crate does not support look-ahead,
+/// so the text would otherwise match exactly what it should exclude.
+///
+/// # Examples
+/// ```
+/// use magic_regexp::{try_not, Digit, Error, Text};
+///
+/// assert_eq!(try_not(Digit).unwrap().to_string(), r"\D");
+/// assert!(matches!(try_not(Text("abc".to_string())), Err(Error::NotNegatable(_))));
+/// ```
+pub fn try_not(t: Type) -> Result<Type> {
+    Ok(match t {
         Type::Digit => Type::NotDigit,
         Type::NotDigit => Type::Digit,
+        Type::DigitNonZero => Type::NotDigitNonZero,
+        Type::NotDigitNonZero => Type::DigitNonZero,
         Type::WordBoundary => Type::NotWordBoundary,
         Type::NotWordBoundary => Type::WordBoundary,
+        Type::Word => Type::NotWord,
+        Type::NotWord => Type::Word,
         Type::WordChar => Type::NotWordChar,
         Type::NotWordChar => Type::WordChar,
         Type::Whitespace => Type::NotWhitespace,
         Type::NotWhitespace => Type::Whitespace,
+        Type::SpaceOnly => Type::NotSpaceOnly,
+        Type::NotSpaceOnly => Type::SpaceOnly,
         Type::Letter => Type::NotLetter,
         Type::NotLetter => Type::Letter,
+        Type::UnicodeLetter => Type::NotUnicodeLetter,
+        Type::NotUnicodeLetter => Type::UnicodeLetter,
         Type::LetterLowercase => Type::NotLetterLowercase,
         Type::NotLetterLowercase => Type::LetterLowercase,
         Type::LetterUppercase => Type::NotLetterUppercase,
@@ -110,26 +317,124 @@ pub fn not(t: Type) -> Type {
         Type::NotLinefeed => Type::Linefeed,
         Type::CarriageReturn => Type::NotCarriageReturn,
         Type::NotCarriageReturn => Type::CarriageReturn,
-        Type::Text(t) => Type::Text(format!("^{}", t)),
-        Type::Options(t) => {
-            if let Some(first) = t.chars().next() {
-                let opt = if first == '^' {
-                    t[1..].to_string()
-                } else {
-                    format!("^{}", t)
-                };
-                Type::Options(opt)
-            } else {
-                panic!("Invalid options: {}", t);
-            }
-        }
+        Type::Null => Type::NotNull,
+        Type::NotNull => Type::Null,
+        Type::Emoji => Type::NotEmoji,
+        Type::NotEmoji => Type::Emoji,
+        Type::Bell => Type::NotBell,
+        Type::NotBell => Type::Bell,
+        Type::FormFeed => Type::NotFormFeed,
+        Type::NotFormFeed => Type::FormFeed,
+        Type::VerticalTab => Type::NotVerticalTab,
+        Type::NotVerticalTab => Type::VerticalTab,
+        Type::Punctuation => Type::NotPunctuation,
+        Type::NotPunctuation => Type::Punctuation,
+        Type::HexDigit => Type::NotHexDigit,
+        Type::NotHexDigit => Type::HexDigit,
+        Type::Alphanumeric => Type::NotAlphanumeric,
+        Type::NotAlphanumeric => Type::Alphanumeric,
+        Type::Posix(class) => Type::NotPosix(class),
+        Type::NotPosix(class) => Type::Posix(class),
+        Type::UnicodeCategory(name) => Type::NotUnicodeCategory(name),
+        Type::NotUnicodeCategory(name) => Type::UnicodeCategory(name),
+        Type::CharInRange(start, end) => Type::NotCharInRange(start, end),
+        Type::NotCharInRange(start, end) => Type::CharInRange(start, end),
+        Type::Options(options) => Type::NotOptions(options),
+        Type::NotOptions(options) => Type::Options(options),
+        Type::AnyOf(chars) => Type::NotAnyOf(chars),
+        Type::NotAnyOf(chars) => Type::AnyOf(chars),
+        Type::Text(_) => return Err(Error::NotNegatable(t)),
         _ => t,
+    })
+}
+
+/// Returns the given text with all regex meta characters escaped, so it is matched literally.
+/// This is the same escaping, which `Input::Exactly(Type::Text)` uses.
+///
+/// # Example
+/// ```
+/// use magic_regexp::escape;
+///
+/// assert_eq!(escape("a.b*c"), r"a\.b\*c");
+/// ```
+pub fn escape(input: &str) -> String {
+    // Contains all meta characters of the regex crate outside of a character class,
+    // including `#`, which starts a comment in extended mode.
+    const META_CHARS: &str = r".*+?^${}()|[]\/#";
+
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if META_CHARS.contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
     }
+    escaped
+}
+
+/// A character class, which matches no character at all, so the regex never matches.
+/// An empty group `(?:)` would match everywhere instead.
+const NEVER_MATCHES: &str = r"[^\s\S]";
+
+/// Returns a regex, which matches any of the given texts literally.
+/// The texts are escaped like `Input::Exactly(Type::Text)` does.
+/// Longer texts are tried first, so a text is not cut short by one of its prefixes.
+/// Without any texts, the regex never matches, like an empty alternation.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{any_of, create_reg_exp};
+///
+/// let regex = create_reg_exp(any_of(&["foo", "foobar", "a.b"])).unwrap();
+/// assert_eq!(regex.as_str(), r"(?:foobar|foo|a\.b)");
+/// assert_eq!(regex.find("foobar").unwrap().as_str(), "foobar");
+/// assert!(!regex.is_match("axb"));
+/// ```
+pub fn any_of(texts: &[&str]) -> Pattern {
+    let mut texts = texts.to_vec();
+    texts.sort_by_key(|text| std::cmp::Reverse(text.len()));
+    if texts.is_empty() {
+        return Pattern::from_string(NEVER_MATCHES.to_string());
+    }
+    let alternatives = texts.into_iter().map(escape).collect::<Vec<_>>();
+    Pattern::from_string(format!("(?:{})", alternatives.join("|")))
+}
+
+/// Returns a regex, which matches any of the given inputs.
+/// The alternation is wrapped in a non-capturing group, so chaining further statements does not change its precedence.
+/// Unlike nested `or` calls, this creates a single flat group.
+/// Without any inputs, the regex never matches like `any_of`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, one_of, Digit, Exactly, OneOrMore, Letter};
+///
+/// let regex = create_reg_exp(one_of(vec![OneOrMore(Digit), Exactly(Letter)])).unwrap();
+/// assert_eq!(regex.as_str(), r"(?:\d+|\b[a-zA-Z]\b)");
+/// assert!(regex.is_match("123"));
+/// assert!(regex.is_match("a"));
+/// ```
+pub fn one_of(inputs: Vec<Input>) -> Pattern {
+    if inputs.is_empty() {
+        return Pattern::from_string(NEVER_MATCHES.to_string());
+    }
+    let alternatives = inputs.iter().map(Input::to_string).collect::<Vec<_>>();
+    inputs.iter().fold(
+        Pattern::from_string(format!("(?:{})", alternatives.join("|"))),
+        Pattern::checked,
+    )
 }
 
 /// This is a regex input that can be used to match a single character or a group of characters.
 /// Can be used to create a regex that matches a single character or a group of characters.
 /// For example, `Input::Exactly(Type::Digit)` will match a single digit.
+/// None of the variants creates a capture group, use `grouped` or `grouped_as` for that.
+///
+/// `Input::Exactly` wraps every type except `Type::Text`, `Type::LineStart` and `Type::LineEnd` in word boundaries `\b...\b`,
+/// so `Exactly(Digit)` does not match the digit in `a1b`. Use `without_boundaries` to drop them.
+///
+/// All other variants quantify the type. A type of more than one atom like `Type::Text("ab")` is wrapped
+/// in a non-capturing group, so `Times(Text("ab"), 2)` matches `abab` and not `abb`.
 ///
 /// # Example
 /// ```
@@ -162,14 +467,93 @@ pub fn not(t: Type) -> Type {
 /// assert!(regex.is_match("a"));
 /// assert!(regex.is_match("1 2"));
 /// ```
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp, Input, Type};
+///
+/// let input = Input::AtLeast(Type::Digit, 3);
+/// assert_eq!(input.to_string(), r"\d{3,}");
+/// let regex = create_reg_exp(input).unwrap();
+/// assert!(regex.is_match("123"));
+/// assert!(regex.is_match("1234"));
+/// assert!(!regex.is_match("12"));
+/// ```
+///
+/// # Example
+/// `AtMost` allows zero repetitions, so it always matches the empty string.
+/// ```
+/// use magic_regexp::{create_reg_exp, Input, Type};
+///
+/// let input = Input::AtMost(Type::WordChar, 5);
+/// assert_eq!(input.to_string(), r"\w{0,5}");
+/// let regex = create_reg_exp(input).unwrap();
+/// assert_eq!(regex.find("abcdefg").unwrap().as_str(), "abcde");
+/// assert!(regex.is_match(""));
+/// ```
+///
+/// # Example
+/// `Between` returns an error from `as_regex`, if `min` is greater than `max`.
+/// ```
+/// use magic_regexp::{AsRegex, Input, Type};
+///
+/// let input = Input::Between(Type::Digit, 2, 4);
+/// assert_eq!(input.to_string(), r"\d{2,4}");
+/// assert!(input.as_regex().unwrap().is_match("123"));
+/// assert!(Input::Between(Type::Digit, 4, 2).as_regex().is_err());
+/// ```
+///
+/// # Example
+/// `LazilyOneOrMore` and `LazilyMaybe` match as few characters as possible.
+/// ```
+/// use magic_regexp::{create_reg_exp, Input, Type};
+///
+/// let input = Input::LazilyOneOrMore(Type::Digit);
+/// assert_eq!(input.to_string(), r"\d+?");
+/// let regex = create_reg_exp(input).unwrap();
+/// assert_eq!(regex.find("123").unwrap().as_str(), "1");
+/// ```
+///
+/// # Example
+/// ```
+/// use magic_regexp::{Input, Type};
+///
+/// assert_eq!(Input::TimesRange(Type::Digit, 2, Some(2)).to_string(), r"\d{2}");
+/// assert_eq!(Input::TimesRange(Type::Digit, 2, Some(4)).to_string(), r"\d{2,4}");
+/// assert_eq!(Input::TimesRange(Type::Digit, 2, None).to_string(), r"\d{2,}");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Input {
     OneOrMore(Type),
     Exactly(Type),
     Maybe(Type),
     Times(Type, usize),
+    AtLeast(Type, usize),
+    AtMost(Type, usize),
+    Between(Type, usize, usize),
+    LazilyOneOrMore(Type),
+    LazilyMaybe(Type),
+    /// Repeats the type at least `min` times and at most `max` times.
+    /// If `max` is `None`, the repetition is unbounded.
+    TimesRange(Type, usize, Option<usize>),
+}
+
+/// A type, which is about to be quantified.
+/// Types, which consist of more than one atom like a text of several characters,
+/// are wrapped in a non-capturing group, so the quantifier repeats all of it and not only the last atom.
+struct Atom<'a>(&'a Type);
+
+impl fmt::Display for Atom<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Type::Text(text) if text.chars().count() != 1 => write!(f, "(?:{})", text),
+            t @ (Type::Word | Type::NotWord | Type::Words) => write!(f, "(?:{})", t),
+            t => write!(f, "{}", t),
+        }
+    }
 }
 
-impl ToString for Input {
+impl fmt::Display for Input {
     /// Returns a string representation of the input.
     /// For example, `Input::Exactly(Type::Digit)` will return `\d`.
     ///
@@ -198,31 +582,77 @@ impl ToString for Input {
     /// use regex::Regex;
     ///
     /// let input = OneOrMore(not(Options("01".to_string())));
-    /// assert_eq!(input.to_string(), r"([^01]+)");
+    /// assert_eq!(input.to_string(), r"[^01]+");
     /// let re = Regex::new(&input.to_string()).unwrap();
     /// assert_eq!(re.replace("1078910", ""), "1010");
     /// ```
-    fn to_string(&self) -> String {
-        const ESCAPE_REPLACE_RE: &str = r"[.*+?^${}()|[\\]\\/]";
-
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Input::OneOrMore(t) => format!("({}+)", t.to_string()),
-            Input::Exactly(t) => match t {
-                Type::Text(t) => Regex::new(ESCAPE_REPLACE_RE)
-                    .expect("Invalid replace_all regex")
-                    .replace_all(t, r"\$0")
-                    .to_string(),
-                _ => format!(r"\b{}\b", t.to_string()),
-            },
-            Input::Maybe(t) => format!("({}?)", t.to_string()),
-            Input::Times(t, n) => format!("{}{{{}}}", t.to_string(), n),
+            Input::OneOrMore(t) => write!(f, "{}+", Atom(t)),
+            Input::Exactly(Type::Text(t)) => f.write_str(&escape(t)),
+            Input::Exactly(t @ (Type::LineStart | Type::LineEnd)) => write!(f, "{}", t),
+            Input::Exactly(t) => write!(f, r"\b{}\b", t),
+            Input::Maybe(t) => write!(f, "{}?", Atom(t)),
+            Input::Times(t, n) => write!(f, "{}{{{}}}", Atom(t), n),
+            Input::AtLeast(t, n) => write!(f, "{}{{{},}}", Atom(t), n),
+            Input::AtMost(t, n) => write!(f, "{}{{0,{}}}", Atom(t), n),
+            Input::Between(t, min, max) => write!(f, "{}{{{},{}}}", Atom(t), min, max),
+            Input::LazilyOneOrMore(t) => write!(f, "{}+?", Atom(t)),
+            Input::LazilyMaybe(t) => write!(f, "{}??", Atom(t)),
+            Input::TimesRange(t, min, Some(max)) if min == max => {
+                write!(f, "{}{{{}}}", Atom(t), min)
+            }
+            Input::TimesRange(t, min, Some(max)) => write!(f, "{}{{{},{}}}", Atom(t), min, max),
+            Input::TimesRange(t, min, None) => write!(f, "{}{{{},}}", Atom(t), min),
+        }
+    }
+}
+
+/// Returns true, if the given name is allowed as a name of a capture group.
+/// It has to start with a letter or `_` and can only contain letters, digits, `_`, `.`, `[` and `]`.
+fn is_valid_group_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '[' | ']'))
         }
+        _ => false,
     }
 }
 
 impl AsRegex for Input {
-    fn as_regex(&self) -> Result<Regex> {
-        Ok(Regex::new(&self.to_string())?)
+    /// Returns the regex of the input, annotated with a comment, which describes it.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{AsRegex, Digit, Times};
+    ///
+    /// assert_eq!(Times(Digit, 4).to_pretty_string(), r"\d{4} // 4 digits");
+    /// ```
+    fn to_pretty_string(&self) -> String {
+        format!("{} // {}", self, self.describe())
+    }
+
+    /// Checks the input, which would otherwise result in an unexpected regex.
+    fn validate(&self) -> Result<()> {
+        match self {
+            Input::OneOrMore(t)
+            | Input::Exactly(t)
+            | Input::Maybe(t)
+            | Input::Times(t, _)
+            | Input::AtLeast(t, _)
+            | Input::AtMost(t, _)
+            | Input::Between(t, _, _)
+            | Input::LazilyOneOrMore(t)
+            | Input::LazilyMaybe(t)
+            | Input::TimesRange(t, _, _) => t.validate()?,
+        }
+        match self {
+            Input::Between(_, min, max) | Input::TimesRange(_, min, Some(max)) if min > max => {
+                Err(Error::InvalidRange(*min, *max))
+            }
+            _ => Ok(()),
+        }
     }
 }
 
@@ -252,13 +682,35 @@ impl Input {
     /// let regex = create_reg_exp(OneOrMore(Digit).grouped_as("digits")).unwrap();
     /// assert_eq!(&regex.captures("1").unwrap()["digits"], "1");
     /// ```
-    pub fn grouped_as(&self, name: &str) -> Regex {
-        Regex::new(&format!(r"(?P<{}>{})", name, self.to_string())).expect("Invalid regex")
+    pub fn grouped_as(&self, name: &str) -> Pattern {
+        Pattern::described(
+            format!(r"(?P<{}>{})", name, self),
+            &format!("{} ({})", self.describe(), name),
+        )
+    }
+
+    /// This defines the entire input so far as a named capture group.
+    /// Returns an error instead of panicking, if the name or the input is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Digit, Error, OneOrMore};
+    ///
+    /// let regex = OneOrMore(Digit).try_grouped_as("digits").unwrap();
+    /// assert_eq!(&regex.captures("1").unwrap()["digits"], "1");
+    /// assert!(matches!(OneOrMore(Digit).try_grouped_as("1bad"), Err(Error::InvalidGroupName(_))));
+    /// ```
+    pub fn try_grouped_as(&self, name: &str) -> Result<Regex> {
+        if !is_valid_group_name(name) {
+            return Err(Error::InvalidGroupName(name.to_string()));
+        }
+        self.validate()?;
+        compile(&format!(r"(?P<{}>{})", name, self))
     }
 
     /// This defines the entire input so far as a named capture group.
     /// This is an alias for `grouped_as`.
-    pub fn r#as(&self, name: &str) -> Regex {
+    pub fn r#as(&self, name: &str) -> Pattern {
         self.grouped_as(name)
     }
 
@@ -289,10 +741,127 @@ impl Input {
     /// assert_eq!(cap, &expected);
     /// ```
     ///
-    pub fn grouped(&self) -> Regex {
-        Regex::new(&format!(r"({})", self.to_string())).expect("Invalid regex")
+    pub fn grouped(&self) -> Pattern {
+        Pattern::described(
+            format!(r"({})", self),
+            &format!("{} (group)", self.describe()),
+        )
+    }
+
+    /// This defines the entire input so far as an anonymous group.
+    /// Returns an error instead of panicking, if the input is invalid.
+    pub fn try_grouped(&self) -> Result<Regex> {
+        self.validate()?;
+        compile(&format!(r"({})", self))
+    }
+
+    /// This defines the entire input so far as a non-capturing group.
+    /// Use this to scope a statement without shifting the indices of the capture groups.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Digit, OneOrMore};
+    ///
+    /// let regex = OneOrMore(Digit).non_capturing();
+    /// assert_eq!(regex.as_str(), r"(?:\d+)");
+    /// assert_eq!(regex.build().unwrap().captures_len(), 1);
+    /// ```
+    pub fn non_capturing(&self) -> Pattern {
+        Pattern::from_statement(self).wrap("(?:", ")", "non-capturing group")
+    }
+
+    /// This removes the word boundaries, which `Input::Exactly` adds around every type except `Type::Text`.
+    /// All other inputs stay the same.
+    ///
+    /// # Example
+    /// ```
+    /// use magic_regexp::{Digit, Exactly};
+    ///
+    /// let bounded = Exactly(Digit).non_capturing().build().unwrap();
+    /// let unbounded = Exactly(Digit).without_boundaries().build().unwrap();
+    /// assert!(!bounded.is_match("a1b"));
+    /// assert!(unbounded.is_match("a1b"));
+    /// ```
+    pub fn without_boundaries(&self) -> Pattern {
+        let regex = match self {
+            Input::Exactly(t) if !matches!(t, Type::Text(_)) => t.to_string(),
+            _ => self.to_string(),
+        };
+        Pattern::from_string(regex).checked(self)
     }
 }
 
 impl AsRegex for Regex {}
 impl Condition for Regex {}
+
+/// Chains the input with the given statement, like `Condition::and`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{Condition, Digit, Exactly, Letter};
+///
+/// let pattern = Exactly(Digit) + Exactly(Letter);
+/// assert_eq!(pattern, Exactly(Digit).and(Exactly(Letter)));
+/// ```
+impl<T: AsRegex> Add<T> for Input {
+    type Output = Pattern;
+
+    fn add(self, other: T) -> Pattern {
+        self.and(other)
+    }
+}
+
+/// Chains the input with the given statement, like `Condition::or`.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{Condition, Digit, Exactly, Letter};
+///
+/// let pattern = Exactly(Digit) | Exactly(Letter);
+/// assert_eq!(pattern, Exactly(Digit).or(Exactly(Letter)));
+/// ```
+impl<T: AsRegex> BitOr<T> for Input {
+    type Output = Pattern;
+
+    fn bitor(self, other: T) -> Pattern {
+        self.or(other)
+    }
+}
+
+/// Chains the pattern with the given statement, like `Condition::and`.
+impl<T: AsRegex> Add<T> for Pattern {
+    type Output = Pattern;
+
+    fn add(self, other: T) -> Pattern {
+        self.and(other)
+    }
+}
+
+/// Chains the pattern with the given statement, like `Condition::or`.
+impl<T: AsRegex> BitOr<T> for Pattern {
+    type Output = Pattern;
+
+    fn bitor(self, other: T) -> Pattern {
+        self.or(other)
+    }
+}
+
+/// Chains an already compiled regex with the given input, like `Condition::and`.
+/// Two regexes cannot be added, because both types are defined outside of this crate.
+impl Add<Input> for Regex {
+    type Output = Pattern;
+
+    fn add(self, other: Input) -> Pattern {
+        self.and(other)
+    }
+}
+
+/// Chains an already compiled regex with the given input, like `Condition::or`.
+/// Two regexes cannot be combined, because both types are defined outside of this crate.
+impl BitOr<Input> for Regex {
+    type Output = Pattern;
+
+    fn bitor(self, other: Input) -> Pattern {
+        self.or(other)
+    }
+}
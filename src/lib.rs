@@ -34,7 +34,9 @@ for caps in re.captures_iter(TO_SEARCH) {
 mod core;
 pub use crate::core::*;
 
-use regex::Regex;
+use crate::core::cache::compile;
+
+use regex::{Regex, RegexBuilder};
 
 /// Returns the regex, which represents the given statement.
 /// This is only for convenience and compatibility with magic-regex from npm.
@@ -65,11 +67,50 @@ pub fn create_reg_exp(input: impl AsRegex) -> Result<Regex> {
     input.as_regex()
 }
 
+/// Returns the regex, which only matches, if the given statement matches the whole text.
+/// This is useful for validation, where a match inside of the text is not enough.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp_anchored, Digit, OneOrMore};
+///
+/// let re = create_reg_exp_anchored(OneOrMore(Digit)).unwrap();
+/// assert!(re.is_match("123"));
+/// assert!(!re.is_match("123abc"));
+/// ```
+pub fn create_reg_exp_anchored(input: impl AsRegex) -> Result<Regex> {
+    input.validate()?;
+    compile(&format!(r"\A(?:{})\z", input.to_string()))
+}
+
+/// Returns the regex, which represents the given statement, compiled with the options of the given closure.
+/// Use this for options of `RegexBuilder`, which have no inline flag, like size limits.
+///
+/// # Example
+/// ```
+/// use magic_regexp::{create_reg_exp_with, Exactly, Text};
+///
+/// let re = create_reg_exp_with(Exactly(Text("abc".to_string())), |builder| {
+///     builder.case_insensitive(true)
+/// })
+/// .unwrap();
+/// assert!(re.is_match("ABC"));
+/// ```
+pub fn create_reg_exp_with<F>(input: impl AsRegex, configure: F) -> Result<Regex>
+where
+    F: FnOnce(&mut RegexBuilder) -> &mut RegexBuilder,
+{
+    input.validate()?;
+    let mut builder = RegexBuilder::new(&input.to_string());
+    Ok(configure(&mut builder).build()?)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{create_reg_exp, not, Exactly, OneOrMore, Type::Digit};
+    use super::*;
     use crate::Input::Maybe;
     use crate::Type::Text;
+    use regex::Regex;
 
     #[test]
     fn test_single_digit() {
@@ -123,4 +164,2104 @@ mod tests {
         assert!(regex.is_match("Hallo welt"));
         assert!(!regex.is_match("Hallo Welt"));
     }
+
+    #[test]
+    fn test_at_least_digits() {
+        let input = AtLeast(Digit, 3);
+        assert_eq!(input.to_string(), r"\d{3,}");
+        let regex = create_reg_exp(input).unwrap();
+        assert!(regex.is_match("123"));
+        assert!(regex.is_match("1234"));
+        assert!(!regex.is_match("12"));
+    }
+
+    #[test]
+    fn test_at_least_grouped_as() {
+        let regex = create_reg_exp(AtLeast(Digit, 3).grouped_as("digits")).unwrap();
+        assert_eq!(&regex.captures("ab1234").unwrap()["digits"], "1234");
+        assert!(regex.captures("ab12").is_none());
+    }
+
+    #[test]
+    fn test_at_most_word_chars() {
+        let input = AtMost(WordChar, 3);
+        assert_eq!(input.to_string(), r"\w{0,3}");
+        let regex = create_reg_exp(input).unwrap();
+        assert_eq!(regex.find("ab").unwrap().as_str(), "ab");
+        assert_eq!(regex.find("abc").unwrap().as_str(), "abc");
+        assert_eq!(regex.find("abcd").unwrap().as_str(), "abc");
+    }
+
+    #[test]
+    fn test_at_most_zero() {
+        let regex = create_reg_exp(AtMost(Digit, 0)).unwrap();
+        assert_eq!(regex.as_str(), r"\d{0,0}");
+        assert!(regex.is_match(""));
+        assert_eq!(regex.find("123").unwrap().as_str(), "");
+    }
+
+    #[test]
+    fn test_at_most_grouped_as() {
+        let regex = create_reg_exp(AtMost(WordChar, 2).grouped_as("chars")).unwrap();
+        assert_eq!(&regex.captures("abc").unwrap()["chars"], "ab");
+    }
+
+    #[test]
+    fn test_between_digits() {
+        let input = Between(Digit, 2, 4);
+        assert_eq!(input.to_string(), r"\d{2,4}");
+        let regex = Regex::new(&format!("^{}$", input)).unwrap();
+        assert!(regex.is_match("12"));
+        assert!(regex.is_match("1234"));
+        assert!(!regex.is_match("1"));
+        assert!(!regex.is_match("12345"));
+    }
+
+    #[test]
+    fn test_between_invalid_range() {
+        let result = create_reg_exp(Between(Digit, 4, 2));
+        assert!(matches!(result, Err(Error::InvalidRange(4, 2))));
+    }
+
+    #[test]
+    fn test_lazily_one_or_more() {
+        let greedy = create_reg_exp(
+            Exactly(Text("<".into()))
+                .and(OneOrMore(Char))
+                .and(Exactly(Text(">".into()))),
+        )
+        .unwrap();
+        let lazy = create_reg_exp(
+            Exactly(Text("<".into()))
+                .and(LazilyOneOrMore(Char))
+                .and(Exactly(Text(">".into()))),
+        )
+        .unwrap();
+        assert_eq!(greedy.find("<a><b>").unwrap().as_str(), "<a><b>");
+        assert_eq!(lazy.find("<a><b>").unwrap().as_str(), "<a>");
+        assert_eq!(lazy.find_iter("<a><b>").count(), 2);
+    }
+
+    #[test]
+    fn test_lazily_maybe() {
+        assert_eq!(LazilyMaybe(Digit).to_string(), r"\d??");
+        let greedy = create_reg_exp(Maybe(Digit)).unwrap();
+        let lazy = create_reg_exp(LazilyMaybe(Digit)).unwrap();
+        assert_eq!(greedy.find("1").unwrap().as_str(), "1");
+        assert_eq!(lazy.find("1").unwrap().as_str(), "");
+    }
+
+    #[test]
+    fn test_at_line_start_and_end() {
+        let regex =
+            create_reg_exp(Exactly(Text("foo".into())).at_line_start().at_line_end()).unwrap();
+        assert_eq!(regex.as_str(), "^foo$");
+        assert!(regex.is_match("foo"));
+        assert!(!regex.is_match("bar\nfoo"));
+        let multiline = Regex::new(&format!("(?m){}", regex.as_str())).unwrap();
+        assert!(multiline.is_match("bar\nfoo"));
+        assert!(!multiline.is_match("bar\nfoo bar"));
+    }
+
+    #[test]
+    fn test_at_line_start_twice() {
+        let regex = Exactly(Text("foo".into()))
+            .at_line_start()
+            .at_line_start()
+            .at_line_end()
+            .at_line_end();
+        assert_eq!(regex.as_str(), "^foo$");
+        let regex = Exactly(Text("foo$".into())).at_line_end();
+        assert_eq!(regex.as_str(), r"foo\$$");
+    }
+
+    #[test]
+    fn test_at_start_and_end() {
+        let regex = create_reg_exp(OneOrMore(Digit).at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A\d+\z");
+        assert!(regex.is_match("123"));
+        assert!(!regex.is_match("a123"));
+        assert!(!regex.is_match("123a"));
+    }
+
+    #[test]
+    fn test_at_start_ignores_multiline() {
+        let regex = Exactly(Digit).at_start();
+        let multiline = Regex::new(&format!("(?m){}", regex.as_str())).unwrap();
+        assert!(multiline.is_match("1\na"));
+        assert!(!multiline.is_match("a\n1"));
+        let regex = Exactly(Digit).at_end();
+        let multiline = Regex::new(&format!("(?m){}", regex.as_str())).unwrap();
+        assert!(multiline.is_match("a\n1"));
+        assert!(!multiline.is_match("1\na"));
+    }
+
+    #[test]
+    fn test_case_insensitive_text() {
+        let regex = create_reg_exp(Exactly(Text("welt".into())).case_insensitive()).unwrap();
+        assert_eq!(regex.as_str(), "(?i:welt)");
+        assert!(regex.is_match("Hallo welt"));
+        assert!(regex.is_match("Hallo Welt"));
+    }
+
+    #[test]
+    fn test_case_insensitive_grouped_as() {
+        let regex = create_reg_exp(
+            Exactly(Text("welt".into()))
+                .grouped_as("world")
+                .case_insensitive(),
+        )
+        .unwrap();
+        assert_eq!(&regex.captures("Hallo WELT").unwrap()["world"], "WELT");
+    }
+
+    #[test]
+    fn test_multiline() {
+        let anchored = Exactly(Text("foo".into())).at_line_start().at_line_end();
+        assert!(!anchored.build().unwrap().is_match("bar\nfoo"));
+        let regex = create_reg_exp(anchored.multiline()).unwrap();
+        assert_eq!(regex.as_str(), "(?m:^foo$)");
+        assert!(regex.is_match("bar\nfoo"));
+        assert!(!regex.is_match("bar\nfoo bar"));
+    }
+
+    #[test]
+    fn test_multiline_case_insensitive() {
+        let regex = create_reg_exp(
+            Exactly(Text("foo".into()))
+                .at_line_start()
+                .at_line_end()
+                .multiline()
+                .case_insensitive(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), "(?i:(?m:^foo$))");
+        assert!(regex.is_match("bar\nFOO"));
+    }
+
+    #[test]
+    fn test_dot_all() {
+        let regex = create_reg_exp(OneOrMore(Char)).unwrap();
+        assert_eq!(regex.find("a\nb").unwrap().as_str(), "a");
+        let regex = create_reg_exp(OneOrMore(Char).dot_all()).unwrap();
+        assert_eq!(regex.as_str(), "(?s:.+)");
+        assert_eq!(regex.find("a\nb").unwrap().as_str(), "a\nb");
+    }
+
+    #[test]
+    fn test_dot_all_is_not_multiline() {
+        let regex = Exactly(Text("b".into()))
+            .at_line_start()
+            .dot_all()
+            .build()
+            .unwrap();
+        assert!(!regex.is_match("a\nb"));
+    }
+
+    #[test]
+    fn test_not_tab() {
+        assert_eq!(not(Tab).to_string(), r"[^\t]");
+        let regex = create_reg_exp(not(Tab)).unwrap();
+        assert!(regex.is_match("a"));
+        assert!(!regex.is_match("\t"));
+        assert_eq!(not(not(Tab)).to_string(), r"\t");
+    }
+
+    #[test]
+    fn test_not_linefeed_and_carriage_return() {
+        assert_eq!(not(Linefeed).to_string(), r"[^\n]");
+        assert_eq!(not(CarriageReturn).to_string(), r"[^\r]");
+        let regex = create_reg_exp(not(Linefeed)).unwrap();
+        assert!(regex.is_match("a"));
+        assert!(!regex.is_match("\n"));
+        let regex = create_reg_exp(not(CarriageReturn)).unwrap();
+        assert!(regex.is_match("a"));
+        assert!(!regex.is_match("\r"));
+    }
+
+    #[test]
+    fn test_or_precedence() {
+        let regex =
+            create_reg_exp(Exactly(Digit).or(Exactly(Letter)).and(Exactly(Whitespace))).unwrap();
+        assert_eq!(regex.as_str(), r"(?:\b\d\b|\b[a-zA-Z]\b)\b\s\b");
+        assert!(regex.is_match("1 x"));
+        assert!(regex.is_match("a x"));
+        assert!(!regex.is_match("1"));
+        assert!(!regex.is_match("a"));
+        assert_eq!(regex.captures_len(), 1);
+    }
+
+    #[test]
+    fn test_optionally_does_not_capture() {
+        let regex = create_reg_exp(
+            Exactly(Text("#".into()))
+                .optionally()
+                .and(OneOrMore(Digit).grouped()),
+        )
+        .unwrap();
+        assert_eq!(&regex.captures("#12").unwrap()[1], "12");
+        assert_eq!(&regex.captures("12").unwrap()[1], "12");
+    }
+
+    #[test]
+    fn test_quantifiers_do_not_capture() {
+        assert_eq!(OneOrMore(Digit).to_string(), r"\d+");
+        assert_eq!(Maybe(Digit).to_string(), r"\d?");
+        assert_eq!(Times(Digit, 2).to_string(), r"\d{2}");
+        let regex = create_reg_exp(
+            OneOrMore(Digit)
+                .and(Maybe(Whitespace))
+                .and(Times(Letter, 2).grouped_as("letters")),
+        )
+        .unwrap();
+        assert_eq!(regex.captures_len(), 2);
+        assert_eq!(&regex.captures("12 ab").unwrap()[1], "ab");
+    }
+
+    #[test]
+    fn test_hex_digit() {
+        let regex = create_reg_exp(OneOrMore(HexDigit)).unwrap();
+        assert_eq!(regex.find("deadBEEF").unwrap().as_str(), "deadBEEF");
+        assert!(!regex.is_match("ghij"));
+        let regex = create_reg_exp(OneOrMore(not(HexDigit))).unwrap();
+        assert_eq!(regex.find("ghij").unwrap().as_str(), "ghij");
+        assert!(matches!(not(not(HexDigit)), HexDigit));
+    }
+
+    #[test]
+    fn test_alphanumeric() {
+        let regex = create_reg_exp(OneOrMore(Alphanumeric).at_start().at_end()).unwrap();
+        assert!(regex.is_match("abCD12"));
+        assert!(!regex.is_match("ab_cd"));
+        let regex = create_reg_exp(OneOrMore(WordChar).at_start().at_end()).unwrap();
+        assert!(regex.is_match("ab_cd"));
+        assert_eq!(not(Alphanumeric).to_string(), "[^a-zA-Z0-9]");
+    }
+
+    #[test]
+    fn test_unicode_category() {
+        let input = OneOrMore(UnicodeCategory("Greek".into()));
+        assert_eq!(input.to_string(), r"\p{Greek}+");
+        let regex = create_reg_exp(input).unwrap();
+        assert_eq!(regex.find("abc αβγ").unwrap().as_str(), "αβγ");
+        assert!(!regex.is_match("abc"));
+        let regex = create_reg_exp(OneOrMore(not(UnicodeCategory("Greek".into())))).unwrap();
+        assert_eq!(regex.as_str(), r"\P{Greek}+");
+        assert_eq!(regex.find("αβγabc").unwrap().as_str(), "abc");
+    }
+
+    #[test]
+    fn test_empty_unicode_category() {
+        let result = create_reg_exp(UnicodeCategory("".into()));
+        assert!(matches!(result, Err(Error::EmptyUnicodeCategory)));
+        let result = create_reg_exp(OneOrMore(not(UnicodeCategory("".into()))));
+        assert!(matches!(result, Err(Error::EmptyUnicodeCategory)));
+    }
+
+    #[test]
+    fn test_char_in_range() {
+        let input = OneOrMore(CharInRange('a', 'f'));
+        assert_eq!(input.to_string(), "[a-f]+");
+        let regex = create_reg_exp(input).unwrap();
+        assert_eq!(regex.find("abcdef").unwrap().as_str(), "abcdef");
+        assert!(!regex.is_match("g"));
+        assert_eq!(not(CharInRange('a', 'f')).to_string(), "[^a-f]");
+    }
+
+    #[test]
+    fn test_char_in_range_escaping() {
+        assert_eq!(CharInRange('-', ']').to_string(), r"[\--\]]");
+        let regex = create_reg_exp(CharInRange('-', ']')).unwrap();
+        assert!(regex.is_match("-"));
+        assert!(regex.is_match("]"));
+        assert!(regex.is_match("A"));
+        assert!(!regex.is_match("a"));
+    }
+
+    #[test]
+    fn test_char_in_range_invalid() {
+        let result = create_reg_exp(CharInRange('f', 'a'));
+        assert!(matches!(result, Err(Error::InvalidCharRange('f', 'a'))));
+        let result = create_reg_exp(Exactly(not(CharInRange('f', 'a'))));
+        assert!(matches!(result, Err(Error::InvalidCharRange('f', 'a'))));
+    }
+
+    #[test]
+    fn test_times_composed() {
+        let regex = Times(Digit, 2).and(Exactly(Text(":".into()))).times(3);
+        assert_eq!(regex.as_str(), r"(?:\d{2}:){3}");
+        let regex = regex.at_start().at_end().build().unwrap();
+        assert!(regex.is_match("12:34:56:"));
+        assert!(!regex.is_match("12:34:"));
+        assert!(!regex.is_match("12:34:56"));
+    }
+
+    #[test]
+    fn test_parse_type_round_trip() {
+        let types = vec![
+            Digit,
+            NotDigit,
+            DigitNonZero,
+            NotDigitNonZero,
+            WordBoundary,
+            NotWordBoundary,
+            Word,
+            NotWord,
+            Words,
+            WordChar,
+            NotWordChar,
+            Text("abc".into()),
+            Options("01".into()),
+            Options("^01".into()),
+            Options("a]b".into()),
+            NotOptions("01".into()),
+            Char,
+            Whitespace,
+            NotWhitespace,
+            SpaceOnly,
+            NotSpaceOnly,
+            Letter,
+            NotLetter,
+            UnicodeLetter,
+            NotUnicodeLetter,
+            LetterLowercase,
+            NotLetterLowercase,
+            LetterUppercase,
+            NotLetterUppercase,
+            Tab,
+            NotTab,
+            Linefeed,
+            NotLinefeed,
+            CarriageReturn,
+            NotCarriageReturn,
+            NewlineAny,
+            Null,
+            NotNull,
+            Bell,
+            NotBell,
+            FormFeed,
+            NotFormFeed,
+            VerticalTab,
+            NotVerticalTab,
+            Emoji,
+            NotEmoji,
+            Punctuation,
+            NotPunctuation,
+            HexDigit,
+            NotHexDigit,
+            Alphanumeric,
+            NotAlphanumeric,
+            Posix(PosixClass::Alpha),
+            NotPosix(PosixClass::Xdigit),
+            UnicodeCategory("Greek".into()),
+            NotUnicodeCategory("Greek".into()),
+            CharInRange('a', 'f'),
+            NotCharInRange('-', ']'),
+        ];
+        for t in types {
+            assert_eq!(t.to_string().parse::<Type>().unwrap(), t);
+        }
+    }
+
+    #[test]
+    fn test_parse_input_round_trip() {
+        let inputs = vec![
+            OneOrMore(Digit),
+            Exactly(Digit),
+            Exactly(Word),
+            Exactly(Text("a.b+".into())),
+            Maybe(Letter),
+            Times(Digit, 4),
+            AtLeast(Digit, 3),
+            AtMost(WordChar, 5),
+            Between(Digit, 2, 4),
+            LazilyOneOrMore(Char),
+            LazilyMaybe(Digit),
+            OneOrMore(Options("+-".into())),
+            Times(Text("ab".into()), 2),
+            OneOrMore(Text("ab".into())),
+            Maybe(Text("ab".into())),
+            OneOrMore(Words),
+            OneOrMore(AnyOf(vec!['a', '-', 'z'])),
+            Times(AnythingBut("a-z".into()), 2),
+            OneOrMore(Options("a-z0-9".into())),
+        ];
+        for input in inputs {
+            assert_eq!(input.to_string().parse::<Input>().unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_type_literal_dash_round_trip() {
+        let types = vec![
+            AnyOf(vec!['a', '-', 'z']),
+            AnythingBut("a-z".into()),
+            Options("a-z0-9".into()),
+            Options("+-".into()),
+        ];
+        for t in types {
+            assert_eq!(t.to_string().parse::<Type>().unwrap(), t);
+        }
+    }
+
+    #[test]
+    fn test_parse_unsupported() {
+        // The quantifier only applies to the last character, which an input cannot represent.
+        assert!(matches!(
+            "ab+".parse::<Input>(),
+            Err(Error::UnsupportedRegex(_))
+        ));
+        assert!(matches!(
+            r"\b\w+\b+".parse::<Input>(),
+            Err(Error::UnsupportedRegex(_))
+        ));
+        // An escaped letter is a class and not the letter itself.
+        assert!(matches!(
+            r"\d".parse::<Input>(),
+            Err(Error::UnsupportedRegex(_))
+        ));
+        assert!(matches!(
+            r"[\d]".parse::<Type>(),
+            Err(Error::UnsupportedRegex(_))
+        ));
+        assert!(matches!(
+            r"(\d+)".parse::<Input>(),
+            Err(Error::UnsupportedRegex(_))
+        ));
+        assert!(matches!(
+            r"\d|\w".parse::<Type>(),
+            Err(Error::UnsupportedRegex(_))
+        ));
+    }
+
+    #[test]
+    fn test_operators() {
+        let regex = Exactly(Digit) + Exactly(Letter) + Exactly(Whitespace);
+        let expected = Exactly(Digit).and(Exactly(Letter)).and(Exactly(Whitespace));
+        assert_eq!(regex.as_str(), expected.as_str());
+
+        let regex = Exactly(Digit) | Exactly(Letter) | Exactly(Whitespace);
+        let expected = Exactly(Digit).or(Exactly(Letter)).or(Exactly(Whitespace));
+        assert_eq!(regex.as_str(), expected.as_str());
+    }
+
+    #[test]
+    fn test_operators_precedence() {
+        let regex = (Exactly(Digit) | Exactly(Letter)) + Exactly(Whitespace);
+        assert_eq!(regex.as_str(), r"(?:\b\d\b|\b[a-zA-Z]\b)\b\s\b");
+        let regex = regex.build().unwrap();
+        assert!(!regex.is_match("1"));
+        assert!(regex.is_match("1 x"));
+    }
+
+    #[test]
+    fn test_any_of() {
+        let regex = create_reg_exp(any_of(&["foo", "bar", "baz"])).unwrap();
+        assert!(regex.is_match("baz"));
+        assert!(!regex.is_match("qux"));
+
+        // Without any texts, nothing matches, not even the empty text.
+        let regex = create_reg_exp(any_of(&[])).unwrap();
+        assert!(!regex.is_match(""));
+        assert!(!regex.is_match("foo"));
+    }
+
+    #[test]
+    fn test_any_of_escapes() {
+        let regex = create_reg_exp(any_of(&["(a)", "b.c"]).at_start().at_end()).unwrap();
+        assert!(regex.is_match("(a)"));
+        assert!(regex.is_match("b.c"));
+        assert!(!regex.is_match("a"));
+        assert!(!regex.is_match("bxc"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a.b*c"), r"a\.b\*c");
+        assert_eq!(escape(r".*+?^${}()|\/"), r"\.\*\+\?\^\$\{\}\(\)\|\\\/");
+        assert_eq!(escape("abc"), "abc");
+        let regex = create_reg_exp(Text(escape("1+1"))).unwrap();
+        assert!(regex.is_match("1+1"));
+    }
+
+    #[test]
+    fn test_exactly_text_as_str() {
+        let regex = create_reg_exp(Exactly(Text("a.b(c)".into()))).unwrap();
+        assert_eq!(regex.as_str(), r"a\.b\(c\)");
+    }
+
+    #[test]
+    fn test_try_grouped_as() {
+        let regex = OneOrMore(Digit).try_grouped_as("digits_1").unwrap();
+        assert_eq!(&regex.captures("a12").unwrap()["digits_1"], "12");
+        for name in ["1bad", "", "with space", "a-b"] {
+            assert!(matches!(
+                OneOrMore(Digit).try_grouped_as(name),
+                Err(Error::InvalidGroupName(_))
+            ));
+        }
+        assert!(matches!(
+            Between(Digit, 4, 2).try_grouped_as("digits"),
+            Err(Error::InvalidRange(4, 2))
+        ));
+    }
+
+    #[test]
+    fn test_try_grouped() {
+        let regex = OneOrMore(Digit).try_grouped().unwrap();
+        assert_eq!(&regex.captures("a12").unwrap()[1], "12");
+        assert!(matches!(
+            Exactly(CharInRange('z', 'a')).try_grouped(),
+            Err(Error::InvalidCharRange('z', 'a'))
+        ));
+    }
+
+    #[test]
+    fn test_non_capturing() {
+        let grouped = create_reg_exp(OneOrMore(Digit).grouped()).unwrap();
+        let non_capturing = create_reg_exp(OneOrMore(Digit).non_capturing()).unwrap();
+        assert_eq!(grouped.captures_len(), 2);
+        assert_eq!(non_capturing.captures_len(), 1);
+        assert_eq!(non_capturing.find("a12").unwrap().as_str(), "12");
+    }
+
+    #[test]
+    fn test_not_text() {
+        assert!(matches!(
+            try_not(Text("abc".into())),
+            Err(Error::NotNegatable(Text(_)))
+        ));
+        assert_eq!(try_not(Digit).unwrap(), NotDigit);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be negated")]
+    fn test_not_text_panics() {
+        not(Text("abc".into()));
+    }
+
+    #[test]
+    fn test_to_pretty_string() {
+        let segments = [
+            Times(Digit, 4),
+            Exactly(Text("-".into())),
+            Times(Digit, 2),
+            OneOrMore(Letter),
+            Maybe(Whitespace),
+        ]
+        .iter()
+        .map(|input| input.to_pretty_string())
+        .collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            [
+                r"\d{4} // 4 digits",
+                r"- // literal '-'",
+                r"\d{2} // 2 digits",
+                r"[a-zA-Z]+ // one or more letters",
+                r"\s? // optional whitespace",
+            ]
+        );
+        assert_eq!(create_reg_exp(Times(Digit, 4)).unwrap().as_str(), r"\d{4}");
+    }
+
+    #[test]
+    fn test_to_pretty_string_combinators() {
+        let pattern = Times(Digit, 4)
+            .grouped_as("year")
+            .and(Exactly(Text("-".into())))
+            .times(2);
+        assert_eq!(
+            pattern.to_pretty_string().lines().collect::<Vec<_>>(),
+            [
+                "(?:",
+                r"  (?P<year>\d{4}) // 4 digits (year)",
+                "  - // literal '-'",
+                "){2} // 2 times",
+            ]
+        );
+        assert_eq!(pattern.to_string(), r"(?:(?P<year>\d{4})-){2}");
+
+        let pattern = OneOrMore(Digit)
+            .and(Exactly(Text("px".into())))
+            .case_insensitive()
+            .prefix("$")
+            .at_start()
+            .at_end();
+        assert_eq!(
+            pattern.to_pretty_string().lines().collect::<Vec<_>>(),
+            [
+                r"\A // start of text",
+                r"\$ // literal '$'",
+                "(?i:",
+                r"  \d+ // one or more digits",
+                "  px // literal 'px'",
+                ") // case-insensitive",
+                r"\z // end of text",
+            ]
+        );
+        assert_eq!(pattern.to_string(), r"\A\$(?i:\d+px)\z");
+    }
+
+    #[test]
+    fn test_to_pretty_string_date() {
+        let date = Times(Digit, 4)
+            .grouped_as("year")
+            .and(Exactly(Text("-".into())))
+            .and(Times(Digit, 2).grouped_as("month"))
+            .and(Exactly(Text("-".into())))
+            .and(Times(Digit, 2).grouped_as("day"));
+        assert_eq!(
+            date.to_pretty_string().lines().collect::<Vec<_>>(),
+            [
+                r"(?P<year>\d{4}) // 4 digits (year)",
+                r"- // literal '-'",
+                r"(?P<month>\d{2}) // 2 digits (month)",
+                r"- // literal '-'",
+                r"(?P<day>\d{2}) // 2 digits (day)",
+            ]
+        );
+        assert_eq!(
+            create_reg_exp(date).unwrap().as_str(),
+            r"(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})"
+        );
+
+        // Groups and alternations around a chain indent the segments inside of them.
+        let time = Times(Digit, 2)
+            .and(Exactly(Text(":".into())))
+            .and(Times(Digit, 2))
+            .grouped_as("time")
+            .and(
+                Exactly(Text("am".into()))
+                    .or(Exactly(Text("pm".into())))
+                    .optionally(),
+            );
+        assert_eq!(
+            time.to_pretty_string().lines().collect::<Vec<_>>(),
+            [
+                "(?P<time>",
+                r"  \d{2} // 2 digits",
+                "  : // literal ':'",
+                r"  \d{2} // 2 digits",
+                ") // time",
+                "(?:",
+                "  (?:",
+                "    am // literal 'am'",
+                "  |",
+                "    pm // literal 'pm'",
+                "  )",
+                ")? // optional",
+            ]
+        );
+        assert_eq!(time.to_string(), r"(?P<time>\d{2}:\d{2})(?:(?:am|pm))?");
+    }
+
+    #[test]
+    fn test_times_range() {
+        let exactly = create_reg_exp(TimesRange(Digit, 3, Some(3)).at_start().at_end()).unwrap();
+        assert_eq!(exactly.as_str(), r"\A\d{3}\z");
+        assert!(exactly.is_match("123"));
+        assert!(!exactly.is_match("1234"));
+
+        let at_least = create_reg_exp(TimesRange(Digit, 3, None).at_start().at_end()).unwrap();
+        assert_eq!(at_least.as_str(), r"\A\d{3,}\z");
+        assert!(at_least.is_match("12345"));
+        assert!(!at_least.is_match("12"));
+
+        let ranged = create_reg_exp(TimesRange(Digit, 2, Some(4)).at_start().at_end()).unwrap();
+        assert_eq!(ranged.as_str(), r"\A\d{2,4}\z");
+        assert!(ranged.is_match("12"));
+        assert!(ranged.is_match("1234"));
+        assert!(!ranged.is_match("12345"));
+    }
+
+    #[test]
+    fn test_times_range_invalid() {
+        assert!(matches!(
+            create_reg_exp(TimesRange(Digit, 4, Some(2))),
+            Err(Error::InvalidRange(4, 2))
+        ));
+    }
+
+    #[test]
+    fn test_as_whole_word() {
+        let regex = create_reg_exp(Exactly(Text("cat".into())).as_whole_word()).unwrap();
+        assert_eq!(regex.as_str(), r"\b(?:cat)\b");
+        assert!(regex.is_match("the cat sleeps"));
+        assert!(regex.is_match("cat"));
+        assert!(!regex.is_match("category"));
+        assert!(!regex.is_match("bobcat"));
+    }
+
+    #[test]
+    fn test_as_whole_word_alternation() {
+        let regex = Exactly(Text("cat".into()))
+            .or(Exactly(Text("dog".into())))
+            .as_whole_word()
+            .build()
+            .unwrap();
+        assert!(regex.is_match("a dog"));
+        assert!(!regex.is_match("dogma"));
+        assert!(!regex.is_match("bobcat"));
+    }
+
+    #[test]
+    fn test_named_captures() {
+        let regex = create_reg_exp(
+            Times(Digit, 4)
+                .grouped_as("year")
+                .and(Exactly(Text("-".to_string())))
+                .and(Times(Digit, 2).grouped_as("month"))
+                .and(Exactly(Text("-".to_string())))
+                .and(Times(Digit, 2).grouped_as("day")),
+        )
+        .unwrap();
+        let captures = named_captures(&regex, "On 2010-03-14, foo happened.").unwrap();
+        assert_eq!(captures.len(), 3);
+        assert_eq!(captures["year"], "2010");
+        assert_eq!(captures["month"], "03");
+        assert_eq!(captures["day"], "14");
+        assert!(named_captures(&regex, "no date").is_none());
+    }
+
+    #[test]
+    fn test_options_escaping() {
+        let regex = create_reg_exp(Options("a]b".into())).unwrap();
+        assert_eq!(regex.as_str(), r"[a\]b]");
+        assert!(regex.is_match("]"));
+        assert!(!regex.is_match("c"));
+
+        let regex = create_reg_exp(Options("^a".into())).unwrap();
+        assert!(regex.is_match("^"));
+        assert!(regex.is_match("a"));
+        assert!(!regex.is_match("b"));
+
+        let regex = create_reg_exp(Options("a-c".into())).unwrap();
+        assert!(regex.is_match("b"));
+    }
+
+    #[test]
+    fn test_not_options() {
+        assert_eq!(not(Options("^a".into())), NotOptions("^a".into()));
+        let regex = create_reg_exp(not(Options("^a".into()))).unwrap();
+        assert_eq!(regex.as_str(), r"[^\^a]");
+        assert!(regex.is_match("b"));
+        assert!(!regex.is_match("^"));
+        assert!(!regex.is_match("a"));
+    }
+
+    #[test]
+    fn test_create_reg_exp_anchored() {
+        let input = OneOrMore(WordChar)
+            .grouped_as("user")
+            .and(Exactly(Text("@".into())))
+            .and(OneOrMore(WordChar).grouped_as("host"))
+            .and(Exactly(Text(".".into())))
+            .and(OneOrMore(Letter));
+        let regex = create_reg_exp_anchored(input).unwrap();
+        assert!(regex.is_match("john@example.com"));
+        assert!(!regex.is_match("john@example.com!!"));
+        assert!(!regex.is_match(" john@example.com"));
+        let captures = regex.captures("john@example.com").unwrap();
+        assert_eq!(&captures["user"], "john");
+        assert_eq!(&captures["host"], "example");
+    }
+
+    #[test]
+    fn test_anything_but() {
+        let input = AnythingBut("\"".into());
+        assert_eq!(input.to_string(), "[^\"]");
+        let regex = create_reg_exp(input).unwrap();
+        assert!(regex.is_match("a"));
+        assert!(!regex.is_match("\""));
+
+        let regex = create_reg_exp(AnythingBut("a-c".into())).unwrap();
+        assert!(regex.is_match("b"));
+        assert!(!regex.is_match("-"));
+        assert_eq!(not(AnythingBut("a".into())), AnythingBut("a".into()));
+        assert_eq!(not(Char), Char);
+    }
+
+    #[test]
+    fn test_escape_meta_characters() {
+        // `&`, `-` and `~` are only special inside of a character class.
+        let meta_characters = [
+            ('.', r"\."),
+            ('*', r"\*"),
+            ('+', r"\+"),
+            ('?', r"\?"),
+            ('^', r"\^"),
+            ('$', r"\$"),
+            ('{', r"\{"),
+            ('}', r"\}"),
+            ('(', r"\("),
+            (')', r"\)"),
+            ('|', r"\|"),
+            ('[', r"\["),
+            (']', r"\]"),
+            ('\\', r"\\"),
+            ('/', r"\/"),
+            ('#', r"\#"),
+            ('&', "&"),
+            ('-', "-"),
+            ('~', "~"),
+        ];
+        for (c, escaped) in meta_characters {
+            let input = Exactly(Text(c.to_string()));
+            assert_eq!(input.to_string(), escaped);
+            let regex = create_reg_exp(input).unwrap();
+            assert!(regex.is_match(&c.to_string()));
+            assert!(!regex.is_match("a"));
+        }
+    }
+
+    #[test]
+    fn test_digits_and_letters() {
+        assert_eq!(digits(4).to_string(), r"\d{4}");
+        assert_eq!(letters(2).to_string(), "[a-zA-Z]{2}");
+        assert_eq!(one_or_more_digits(), OneOrMore(Digit));
+        assert_eq!(one_or_more_letters(), OneOrMore(Letter));
+        let regex = create_reg_exp(digits(4).grouped_as("year")).unwrap();
+        assert_eq!(&regex.captures("in 2014").unwrap()["year"], "2014");
+    }
+
+    #[test]
+    fn test_ipv4() {
+        let regex = ipv4().build().unwrap();
+        for ip in ["192.168.0.1", "0.0.0.0", "255.255.255.255", "10.249.199.99"] {
+            assert!(regex.is_match(ip), "{}", ip);
+        }
+        for ip in ["256.1.1.1", "1.1.1.256", "1.300.1.1", "1.1.1", "01.1.1.1"] {
+            assert!(!regex.is_match(ip), "{}", ip);
+        }
+        assert_eq!(regex.find("host 10.0.0.1:80").unwrap().as_str(), "10.0.0.1");
+    }
+
+    #[test]
+    fn test_repeat_text() {
+        let regex = create_reg_exp(repeat_text("ab", 2).at_start().at_end()).unwrap();
+        assert!(regex.is_match("abab"));
+        assert!(!regex.is_match("aba"));
+        assert!(!regex.is_match("abb"));
+        let regex = repeat_text("a.", 2);
+        assert_eq!(regex.as_str(), r"(?:a\.){2}");
+        assert!(!regex.build().unwrap().is_match("abab"));
+    }
+
+    #[test]
+    fn test_without_boundaries() {
+        let with_boundaries = create_reg_exp(Exactly(Digit)).unwrap();
+        assert!(!with_boundaries.is_match("a1b"));
+        assert!(with_boundaries.is_match("a 1 b"));
+        let without_boundaries = create_reg_exp(Exactly(Digit).without_boundaries()).unwrap();
+        assert_eq!(without_boundaries.as_str(), r"\d");
+        assert_eq!(without_boundaries.find("a1b").unwrap().as_str(), "1");
+        assert_eq!(
+            Exactly(Text("a.b".into())).without_boundaries().as_str(),
+            r"a\.b"
+        );
+    }
+
+    #[test]
+    fn test_find_all() {
+        let regex = create_reg_exp(
+            Times(Digit, 4)
+                .and(Exactly(Text("-".to_string())))
+                .and(Times(Digit, 2))
+                .and(Exactly(Text("-".to_string())))
+                .and(Times(Digit, 2)),
+        )
+        .unwrap();
+        const TO_SEARCH: &str = "On 2010-03-14, foo happened. On 2014-10-14, bar happened.";
+        assert_eq!(find_all(&regex, TO_SEARCH), ["2010-03-14", "2014-10-14"]);
+        assert!(find_all(&regex, "nothing").is_empty());
+    }
+
+    #[test]
+    fn test_pattern_date() {
+        let pattern = Pattern::new()
+            .push_input(Times(Digit, 4))
+            .and(Exactly(Text("-".to_string())))
+            .push_input(Times(Digit, 2))
+            .and(Exactly(Text("-".to_string())))
+            .push_input(Times(Digit, 2));
+        let chained = Times(Digit, 4)
+            .and(Exactly(Text("-".to_string())))
+            .and(Times(Digit, 2))
+            .and(Exactly(Text("-".to_string())))
+            .and(Times(Digit, 2));
+        let regex = pattern.build().unwrap();
+        assert_eq!(regex.as_str(), chained.as_str());
+        assert!(regex.is_match("2014-01-01"));
+        assert_eq!(create_reg_exp(pattern).unwrap().as_str(), chained.as_str());
+    }
+
+    #[test]
+    fn test_pattern_or_optionally() {
+        let pattern = Pattern::new()
+            .push_type(Digit)
+            .or(Exactly(Letter))
+            .optionally()
+            .push_type(Whitespace);
+        assert_eq!(pattern.as_str(), r"(?:(?:\d|\b[a-zA-Z]\b))?\s");
+        let regex = pattern.build().unwrap();
+        assert!(regex.is_match("1 "));
+        assert!(regex.is_match(" "));
+    }
+
+    #[test]
+    fn test_chain_compiles_once() {
+        // An unbalanced fragment is fine, as long as the whole chain is valid in the end.
+        let open = Pattern::from_string("(?:".to_string());
+        let close = Pattern::from_string(")".to_string());
+        let pattern = open.and(Exactly(Digit)).and(close);
+        assert_eq!(pattern.as_str(), r"(?:\b\d\b)");
+        assert!(pattern.build().is_ok());
+
+        let mut pattern = Pattern::new();
+        for _ in 0..100 {
+            pattern = pattern.and(Exactly(Text("ab".into())));
+        }
+        let regex = create_reg_exp(pattern.at_start().at_end()).unwrap();
+        assert!(regex.is_match(&"ab".repeat(100)));
+        assert!(!regex.is_match(&"ab".repeat(99)));
+    }
+
+    #[test]
+    fn test_words() {
+        let regex = create_reg_exp(Exactly(Words).at_start().at_end()).unwrap();
+        assert!(regex.is_match("John Doe"));
+        assert!(regex.is_match("John"));
+        assert!(!regex.is_match("John "));
+
+        let regex = create_reg_exp(Exactly(Word)).unwrap();
+        assert_eq!(regex.find("John Doe").unwrap().as_str(), "John");
+        let regex = create_reg_exp(Exactly(Words)).unwrap();
+        assert_eq!(regex.find("Hi, John Doe!").unwrap().as_str(), "Hi");
+        assert_eq!(regex.find("-- John  Doe!").unwrap().as_str(), "John  Doe");
+
+        assert_eq!(not(Word), NotWord);
+        assert_eq!(not(NotWord), Word);
+        assert_eq!(not(Words), Words);
+        let regex = create_reg_exp(Exactly(Word).and(NotWord).and(Exactly(Word))).unwrap();
+        assert!(regex.is_match("John, Doe"));
+    }
+
+    #[test]
+    fn test_word_boundary_helpers() {
+        let cat = Exactly(Text("cat".into()));
+        let regex = create_reg_exp(cat.clone().then_boundary().and(Whitespace)).unwrap();
+        assert_eq!(regex.as_str(), r"cat\b\s");
+        assert!(regex.is_match("cat "));
+        assert!(regex.is_match("bobcat "));
+
+        let regex = create_reg_exp(cat.clone().starting_word()).unwrap();
+        assert_eq!(regex.as_str(), r"\b(?:cat)");
+        assert!(regex.is_match("cats "));
+        assert!(!regex.is_match("bobcat "));
+
+        let regex = create_reg_exp(cat.clone().ending_word()).unwrap();
+        assert_eq!(regex.as_str(), r"(?:cat)\b");
+        assert!(regex.is_match("bobcat "));
+        assert!(!regex.is_match("cats "));
+
+        // The boundary is placed after the quantifier, so it is not repeated.
+        let regex = create_reg_exp(OneOrMore(Digit).ending_word()).unwrap();
+        assert_eq!(regex.as_str(), r"(?:\d+)\b");
+        assert_eq!(regex.find("123a 45 ").unwrap().as_str(), "45");
+
+        // The boundary applies to all alternatives.
+        let regex = create_reg_exp(
+            Exactly(Text("a".into()))
+                .or(Exactly(Text("b".into())))
+                .starting_word(),
+        )
+        .unwrap();
+        assert!(!regex.is_match("xb"));
+    }
+
+    #[test]
+    fn test_count_matches() {
+        let regex = create_reg_exp(Exactly(Text("aa".into()))).unwrap();
+        assert_eq!(count_matches(&regex, "aaa"), 1);
+        assert_eq!(count_overlapping(&regex, "aaa"), 2);
+        assert_eq!(count_overlapping(&regex, "aaaa"), 3);
+        assert_eq!(count_overlapping(&regex, "b"), 0);
+
+        // Multi-byte characters are stepped over as a whole.
+        let regex = create_reg_exp(Exactly(Text("ää".into()))).unwrap();
+        assert_eq!(count_overlapping(&regex, "äää"), 2);
+
+        // An empty match does not loop forever.
+        let regex = create_reg_exp(Maybe(Digit)).unwrap();
+        assert_eq!(count_overlapping(&regex, "ab"), 3);
+        assert_eq!(count_matches(&regex, "ab"), 3);
+    }
+
+    #[test]
+    fn test_one_of() {
+        let regex = create_reg_exp(one_of(vec![])).unwrap();
+        assert!(!regex.is_match(""));
+        assert!(!regex.is_match("a"));
+
+        let regex = create_reg_exp(
+            // `Exactly` would add word boundaries, which never match around a single whitespace.
+            one_of(vec![
+                Times(Digit, 1),
+                Times(Letter, 1),
+                Times(Whitespace, 1),
+            ])
+            .at_start()
+            .at_end(),
+        )
+        .unwrap();
+        assert!(regex.is_match("1"));
+        assert!(regex.is_match("a"));
+        assert!(regex.is_match(" "));
+        assert!(!regex.is_match("!"));
+        assert!(!regex.is_match("1a"));
+
+        // The alternation is grouped, so the following statement applies to all of it.
+        let regex = create_reg_exp(
+            one_of(vec![Exactly(Text("a".into())), Exactly(Text("b".into()))])
+                .and(Exactly(Text("c".into())))
+                .at_start(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), r"\A(?:a|b)c");
+        assert!(!regex.is_match("a"));
+        assert!(regex.is_match("bc"));
+    }
+
+    #[test]
+    fn test_any_of_chars() {
+        let vowels = AnyOf(vec!['a', 'e', 'i', 'o', 'u']);
+        assert_eq!(vowels.to_string(), "[aeiou]");
+        let regex = create_reg_exp(OneOrMore(vowels.clone()).at_start().at_end()).unwrap();
+        assert!(regex.is_match("aeu"));
+        assert!(!regex.is_match("abc"));
+
+        assert_eq!(not(vowels.clone()), NotAnyOf(vec!['a', 'e', 'i', 'o', 'u']));
+        assert_eq!(not(vowels.clone()).to_string(), "[^aeiou]");
+        assert_eq!(not(not(vowels.clone())), vowels);
+
+        // `-`, `]` and `^` are taken literally.
+        let set = AnyOf(vec!['^', 'a', '-', 'c', ']']);
+        assert_eq!(set.to_string(), r"[\^a\-c\]]");
+        let regex = create_reg_exp(set).unwrap();
+        assert!(regex.is_match("-"));
+        assert!(regex.is_match("]"));
+        assert!(regex.is_match("^"));
+        assert!(!regex.is_match("b"));
+    }
+
+    #[test]
+    fn test_repeated_with_separator() {
+        let comma = Exactly(Text(",".into()));
+        let regex = create_reg_exp(
+            OneOrMore(Digit)
+                .repeated_with_separator(comma.clone(), 1)
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), r"\A(?:\d+)(?:,(?:\d+)){0,}\z");
+        assert!(regex.is_match("1,2,3"));
+        assert!(regex.is_match("1"));
+        assert!(!regex.is_match(""));
+        assert!(!regex.is_match("1,"));
+
+        let regex = create_reg_exp(
+            OneOrMore(Digit)
+                .repeated_with_separator(comma.clone(), 3)
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert!(regex.is_match("1,2,3"));
+        assert!(!regex.is_match("1,2"));
+
+        let regex = create_reg_exp(
+            OneOrMore(Digit)
+                .repeated_with_separator(comma, 0)
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert!(regex.is_match(""));
+        assert!(regex.is_match("1,2"));
+    }
+
+    #[test]
+    fn test_unicode_letter() {
+        let regex = create_reg_exp(OneOrMore(UnicodeLetter).at_start().at_end()).unwrap();
+        assert!(regex.is_match("josé"));
+        assert!(regex.is_match("Ærø"));
+        assert!(!regex.is_match("jos3"));
+
+        // The ASCII letter does not match accented letters.
+        let regex = create_reg_exp(OneOrMore(Letter).at_start().at_end()).unwrap();
+        assert!(!regex.is_match("josé"));
+
+        assert_eq!(not(UnicodeLetter), NotUnicodeLetter);
+        let regex = create_reg_exp(OneOrMore(NotUnicodeLetter).at_start().at_end()).unwrap();
+        assert!(regex.is_match("123"));
+        assert!(!regex.is_match("é"));
+    }
+
+    #[test]
+    fn test_maybe_composed() {
+        let prefix = Exactly(Text("www".into())).and(Exactly(Text(".".into())));
+        let regex = create_reg_exp(
+            prefix
+                .maybe()
+                .and(Exactly(Text("site".into())))
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), r"\A(?:www\.)?site\z");
+        assert_eq!(regex.captures_len(), 1);
+        assert!(regex.is_match("www.site"));
+        assert!(regex.is_match("site"));
+        assert!(!regex.is_match("www.") && !regex.is_match("wwwsite"));
+    }
+
+    #[test]
+    fn test_group_map() {
+        let pattern = Times(Digit, 4)
+            .grouped_as("year")
+            .and(Exactly(Text("(-)".into())))
+            .and(Times(Digit, 2).grouped())
+            .and(Options("()".into()))
+            .and(Times(Digit, 2).non_capturing())
+            .and(Pattern::from_string(format!(
+                "({})",
+                Times(Digit, 2)
+                    .grouped_as("day")
+                    .and(Maybe(Letter).grouped())
+            )));
+        let groups = pattern.group_map();
+        assert_eq!(groups.len(), 5);
+        assert_eq!(
+            groups[&1],
+            (Some("year".to_string()), r"(?P<year>\d{4})".to_string())
+        );
+        assert_eq!(groups[&2], (None, r"(\d{2})".to_string()));
+        assert_eq!(
+            groups[&3],
+            (None, r"((?P<day>\d{2})([a-zA-Z]?))".to_string())
+        );
+        assert_eq!(
+            groups[&4],
+            (Some("day".to_string()), r"(?P<day>\d{2})".to_string())
+        );
+
+        assert_eq!(groups[&5], (None, r"([a-zA-Z]?)".to_string()));
+
+        // The indices are the same as the ones of the compiled regex.
+        let regex = pattern.build().unwrap();
+        let captures = regex.captures("2014(-)01(1002x").unwrap();
+        assert_eq!(&captures[2], "01");
+        assert_eq!(&captures[4], "02");
+        assert_eq!(&captures[5], "x");
+        assert_eq!(captures.len(), groups.len() + 1);
+    }
+
+    #[test]
+    fn test_or_empty() {
+        let regex = create_reg_exp(
+            Exactly(Text("www.".into()))
+                .or_empty()
+                .and(Exactly(Text("site".into())))
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), r"\A(?:www\.|)site\z");
+        assert!(regex.is_match("www.site"));
+        assert!(regex.is_match("site"));
+        assert!(!regex.is_match("ww.site"));
+
+        // The given statement is preferred over the empty branch.
+        let regex = create_reg_exp(OneOrMore(Digit).or_empty()).unwrap();
+        assert_eq!(regex.find("12").unwrap().as_str(), "12");
+
+        // A repeated empty branch still finishes quickly.
+        let regex = create_reg_exp(
+            Maybe(Letter)
+                .or_empty()
+                .times(30)
+                .and(Exactly(Text("!".into()))),
+        )
+        .unwrap();
+        assert!(!regex.is_match(&"a".repeat(30)));
+    }
+
+    #[test]
+    fn test_to_regex_string() {
+        let t = Digit;
+        assert_eq!(t.to_regex_string(), t.as_regex().unwrap().as_str());
+        let input = Times(Letter, 2);
+        assert_eq!(input.to_regex_string(), input.as_regex().unwrap().as_str());
+        let pattern = input
+            .clone()
+            .and(Exactly(Text(".".into())))
+            .or(OneOrMore(Digit));
+        assert_eq!(pattern.to_regex_string(), pattern.build().unwrap().as_str());
+        let regex = create_reg_exp(input).unwrap();
+        assert_eq!(regex.to_regex_string(), regex.as_str());
+
+        // An invalid statement is returned as it is, because it is not compiled.
+        assert_eq!(Between(Digit, 4, 2).to_regex_string(), r"\d{4,2}");
+    }
+
+    #[test]
+    fn test_integer_and_decimal() {
+        let regex = create_reg_exp(integer().at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A[+-]?\d+\z");
+        for number in ["-42", "+7", "0"] {
+            assert!(regex.is_match(number), "{}", number);
+        }
+        for number in ["", "-", "3.14", "+-1"] {
+            assert!(!regex.is_match(number), "{}", number);
+        }
+
+        let regex = create_reg_exp(decimal().at_start().at_end()).unwrap();
+        for number in ["-42", "+3.14", "0", "0.5"] {
+            assert!(regex.is_match(number), "{}", number);
+        }
+        for number in ["3.", ".5", "1.2.3", "-"] {
+            assert!(!regex.is_match(number), "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_with_flags() {
+        let flags = Flags {
+            case_insensitive: true,
+            ..Flags::default()
+        };
+        let regex = create_reg_exp(Exactly(Text("abc".into())).with_flags(flags).unwrap()).unwrap();
+        assert_eq!(regex.as_str(), "(?i:abc)");
+        assert!(regex.is_match("ABC"));
+
+        let flags = Flags {
+            multiline: true,
+            dot_all: true,
+            ..Flags::default()
+        };
+        let regex = create_reg_exp(
+            Exactly(Char)
+                .without_boundaries()
+                .at_line_start()
+                .and(Exactly(Text("b".into())))
+                .with_flags(flags)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), "(?ms:^.b)");
+        assert!(regex.is_match("a\n\nb"));
+
+        let flags = Flags {
+            case_insensitive: true,
+            multiline: true,
+            dot_all: true,
+            ignore_whitespace: true,
+            swap_greed: true,
+        };
+        assert_eq!(flags.to_string(), "imsxU");
+        let regex = create_reg_exp(OneOrMore(Digit).with_flags(flags).unwrap()).unwrap();
+        assert_eq!(regex.find("123").unwrap().as_str(), "1");
+
+        assert_eq!(
+            OneOrMore(Digit)
+                .with_flags(Flags::default())
+                .unwrap()
+                .as_str(),
+            r"(?:\d+)"
+        );
+
+        // A literal whitespace would be ignored in extended mode.
+        let flags = Flags {
+            ignore_whitespace: true,
+            ..Flags::default()
+        };
+        assert!(matches!(
+            Exactly(Text("a b".into())).with_flags(flags),
+            Err(Error::ConflictingFlags(_))
+        ));
+        assert!(Exactly(Whitespace).with_flags(flags).is_ok());
+        // An unescaped `#` would start a comment in extended mode.
+        assert!(matches!(
+            Exactly(Options("#a".into())).with_flags(flags),
+            Err(Error::ConflictingFlags(_))
+        ));
+        let regex = create_reg_exp(Times(Text(escape("#")), 1).with_flags(flags).unwrap()).unwrap();
+        assert!(regex.is_match("#"));
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_cache() {
+        // The pattern is only used here, so other tests in parallel do not change its hits.
+        let input = || ipv4().and(OneOrMore(Tab)).and(ipv4());
+        let pattern = input().to_string();
+        let first = create_reg_exp(input()).unwrap();
+        assert_eq!(crate::core::cache::hits(&pattern), 0);
+        let second = create_reg_exp(input()).unwrap();
+        assert_eq!(crate::core::cache::hits(&pattern), 1);
+        assert_eq!(first.as_str(), second.as_str());
+        assert!(second.is_match("127.0.0.1\t10.0.0.1"));
+
+        // An invalid pattern is not cached and returns an error every time.
+        assert!(Pattern::from_string("(".to_string()).build().is_err());
+        assert!(Pattern::from_string("(".to_string()).build().is_err());
+        assert_eq!(crate::core::cache::hits("("), 0);
+    }
+
+    #[test]
+    fn test_control_chars() {
+        let regex = create_reg_exp(Times(FormFeed, 1)).unwrap();
+        assert!(regex.is_match("a\x0Cb"));
+        assert!(!regex.is_match("ab"));
+        let regex = create_reg_exp(OneOrMore(not(FormFeed)).at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A[^\f]+\z");
+        assert!(regex.is_match("ab"));
+        assert!(!regex.is_match("a\x0Cb"));
+
+        for (t, c) in [
+            (Null, '\0'),
+            (Bell, '\x07'),
+            (FormFeed, '\x0C'),
+            (VerticalTab, '\x0B'),
+        ] {
+            let regex = create_reg_exp(Times(t.clone(), 1)).unwrap();
+            assert!(regex.is_match(&c.to_string()), "{:?}", t);
+            let regex = create_reg_exp(Times(not(t.clone()), 1)).unwrap();
+            assert!(!regex.is_match(&c.to_string()), "{:?}", t);
+            assert!(regex.is_match("a"), "{:?}", t);
+        }
+    }
+
+    #[test]
+    fn test_words_between() {
+        let regex = create_reg_exp(words_between(2, 5).at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A\b\w+\b(?:\s+\b\w+\b){1,4}\z");
+        assert!(regex.is_match("the quick fox"));
+        assert!(regex.is_match("the  quick\tfox"));
+        assert!(!regex.is_match("fox"));
+        assert!(!regex.is_match("one two three four five six"));
+        assert!(regex.is_match("one two three four five"));
+        // Leading and trailing whitespace is not part of the words.
+        assert!(!regex.is_match(" the quick fox "));
+
+        let regex = create_reg_exp(words_between(0, 1).at_start().at_end()).unwrap();
+        assert!(regex.is_match(""));
+        assert!(regex.is_match("fox"));
+
+        let regex = create_reg_exp(words_between(0, 0).at_start().at_end()).unwrap();
+        assert!(regex.is_match(""));
+        assert!(!regex.is_match("fox"));
+
+        assert!(matches!(
+            words_between(3, 2).build(),
+            Err(Error::InvalidRange(3, 2))
+        ));
+        assert!(matches!(
+            words_between(1, 0).build(),
+            Err(Error::InvalidRange(1, 0))
+        ));
+    }
+
+    #[test]
+    fn test_as_bytes_regex() {
+        let input = Times(Digit, 4)
+            .and(Exactly(Text("-".into())))
+            .and(Times(Digit, 2));
+        let regex = input.as_bytes_regex().unwrap();
+        assert_eq!(regex.as_str(), input.as_regex().unwrap().as_str());
+        let log: &[u8] = b"\xFF\xFEdate 2014-01 \xC3";
+        assert_eq!(regex.find(log).unwrap().as_bytes(), b"2014-01");
+        assert!(!regex.is_match(b"\xFF2014"));
+
+        // A char only matches a valid UTF-8 character.
+        let regex = Times(Char, 1).as_bytes_regex().unwrap();
+        assert!(!regex.is_match(b"\xFF"));
+        assert!(regex.is_match("é".as_bytes()));
+
+        // The validation of the statement still applies.
+        assert!(Between(Digit, 4, 2).as_bytes_regex().is_err());
+    }
+
+    #[test]
+    fn test_anchored_find() {
+        let regex = create_reg_exp(
+            Times(Digit, 4)
+                .and(Exactly(Text("-".into())))
+                .and(Times(Digit, 2)),
+        )
+        .unwrap();
+        let find = |regex: &Regex, text| anchored_find(regex, text).unwrap().map(|m| m.as_str());
+        assert_eq!(find(&regex, "2014-01"), Some("2014-01"));
+        assert_eq!(find(&regex, "2014-01-01"), None);
+        assert_eq!(find(&regex, "On 2014-01"), None);
+        assert_eq!(find(&regex, "2014-01\n"), None);
+
+        // An alternation is anchored as a whole.
+        let regex = Regex::new("a|b").unwrap();
+        assert_eq!(find(&regex, "b"), Some("b"));
+        assert_eq!(find(&regex, "ab"), None);
+
+        // An anchored regex, which cannot be compiled with the default options, is an error and not `None`.
+        let regex = create_reg_exp_with(Times(UnicodeLetter, 300), |builder| {
+            builder.size_limit(1 << 30)
+        })
+        .unwrap();
+        assert!(matches!(
+            anchored_find(&regex, "abc"),
+            Err(Error::RegexError(_))
+        ));
+    }
+
+    #[test]
+    fn test_emoji() {
+        let regex = create_reg_exp(Times(Emoji, 1).at_start().at_end()).unwrap();
+        assert!(regex.is_match("😀"));
+        assert!(!regex.is_match("a"));
+        assert!(!regex.is_match("1"));
+        assert!(!regex.is_match("#"));
+
+        let regex = create_reg_exp(OneOrMore(Emoji)).unwrap();
+        assert_eq!(regex.find("hi 😀😀!").unwrap().as_str(), "😀😀");
+
+        assert_eq!(not(Emoji), NotEmoji);
+        let regex = create_reg_exp(OneOrMore(NotEmoji).at_start().at_end()).unwrap();
+        assert!(regex.is_match("abc"));
+        assert!(!regex.is_match("a😀"));
+    }
+
+    #[test]
+    fn test_and_ref() {
+        let digit = Exactly(Digit);
+        let dash = Exactly(Text("-".into()));
+        let first = digit.and_ref(&dash).and(OneOrMore(Letter));
+        let second = dash.and_ref(&digit);
+        let either = digit.or_ref(&dash);
+        assert_eq!(first.as_str(), r"\b\d\b-[a-zA-Z]+");
+        assert_eq!(second.as_str(), r"-\b\d\b");
+        assert_eq!(either.as_str(), r"(?:\b\d\b|-)");
+        assert!(create_reg_exp(first).unwrap().is_match("1-a"));
+        assert!(create_reg_exp(second).unwrap().is_match("a-1"));
+        // Both sub-patterns are still usable.
+        assert_eq!(digit.to_string(), r"\b\d\b");
+        assert_eq!(dash.to_string(), "-");
+    }
+
+    #[test]
+    fn test_explain() {
+        let date = [
+            Times(Digit, 4),
+            Exactly(Text("-".into())),
+            Times(Digit, 2),
+            Exactly(Text("-".into())),
+            Times(Digit, 2),
+        ];
+        assert_eq!(
+            explain_all(&date),
+            "4 digits followed by literal '-' followed by 2 digits followed by literal '-' followed by 2 digits"
+        );
+        assert_eq!(Maybe(Letter).explain(), "optional letter");
+        assert_eq!(Exactly(Options("a-z".into())).explain(), "one of [a-z]");
+        assert_eq!(Exactly(NotOptions("a-z".into())).explain(), "none of [a-z]");
+        assert_eq!(Exactly(AnyOf(vec!['a', 'b'])).explain(), "one of [ab]");
+        assert_eq!(Times(Digit, 1).explain(), "1 digit");
+        assert_eq!(Whitespace.explain(), "whitespace");
+        assert_eq!(explain_all(&[]), "");
+    }
+
+    #[test]
+    fn test_separated_list_trailing() {
+        let regex = create_reg_exp(
+            OneOrMore(Digit)
+                .separated_list_trailing(Exactly(Text(",".into())))
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), r"\A(?:\d+)(?:,(?:\d+))*(?:,)?\z");
+        assert!(regex.is_match("1,2,3"));
+        assert!(regex.is_match("1,2,3,"));
+        assert!(regex.is_match("1"));
+        assert!(!regex.is_match(",1"));
+        assert!(!regex.is_match("1,,"));
+        assert!(!regex.is_match(""));
+    }
+
+    #[test]
+    fn test_quoted() {
+        let regex = create_reg_exp(double_quoted()).unwrap();
+        assert_eq!(regex.as_str(), r#""(?:[^"\\]|\\.)*""#);
+        let text = r#"x = "he said \"hi\"";"#;
+        assert_eq!(regex.find(text).unwrap().as_str(), r#""he said \"hi\"""#);
+        assert_eq!(regex.find(r#""""#).unwrap().as_str(), r#""""#);
+        assert_eq!(regex.find(r#""a\\" b""#).unwrap().as_str(), r#""a\\""#);
+        assert!(!regex.is_match(r#""unterminated \""#));
+
+        let regex = create_reg_exp(single_quoted()).unwrap();
+        assert_eq!(regex.find(r"say 'it\'s' ok").unwrap().as_str(), r"'it\'s'");
+        let regex = create_reg_exp(backtick_quoted()).unwrap();
+        assert_eq!(regex.find(r"run `a\`b` now").unwrap().as_str(), r"`a\`b`");
+    }
+
+    #[test]
+    fn test_url_components() {
+        let regex = create_reg_exp(scheme().at_start().at_end()).unwrap();
+        assert!(regex.is_match("https"));
+        assert!(regex.is_match("svn+ssh"));
+        assert!(!regex.is_match("1http"));
+        assert!(!regex.is_match("ht tp"));
+
+        let regex = create_reg_exp(host().at_start().at_end()).unwrap();
+        assert!(regex.is_match("example.com"));
+        assert!(regex.is_match("a.b-c.de"));
+        assert!(regex.is_match("localhost"));
+        assert!(!regex.is_match("example..com"));
+        assert!(!regex.is_match("example-.com"));
+
+        let regex = create_reg_exp(path_segment().at_start().at_end()).unwrap();
+        assert!(regex.is_match("index.html"));
+        assert!(regex.is_match("a%20b"));
+        assert!(!regex.is_match("a%2"));
+        assert!(!regex.is_match(""));
+        assert_eq!(percent_encoded().as_str(), "%[0-9a-fA-F]{2}");
+
+        let url = scheme()
+            .and(Exactly(Text("://".into())))
+            .and(host())
+            .and(Exactly(Text("/".into())))
+            .and(path_segment());
+        let regex = create_reg_exp(url.at_start().at_end()).unwrap();
+        assert!(regex.is_match("https://example.com/index.html"));
+        assert!(!regex.is_match("1http://example.com/index.html"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Times(Digit, 4)), r"\d{4}");
+        assert_eq!(format!("{}", Exactly(Text("a.b".into()))), r"a\.b");
+        assert_eq!(format!("{}", Exactly(Digit)), r"\b\d\b");
+        assert_eq!(format!("{}", AnythingBut("-]".into())), r"[^\-\]]");
+        assert_eq!(format!("{:?}", Digit), "Digit");
+        assert_eq!(Times(Digit, 4).to_string(), format!("{}", Times(Digit, 4)));
+    }
+
+    #[test]
+    fn test_group_count() {
+        let pattern = Times(Digit, 4)
+            .grouped_as("year")
+            .and(OneOrMore(Digit).grouped())
+            .and(OneOrMore(Letter).non_capturing())
+            .and(Options("()".into()))
+            .and(Exactly(Text("(".into())))
+            .or(Maybe(Digit).grouped());
+        assert_eq!(pattern.group_count(), 3);
+        assert_eq!(
+            pattern.group_count() + 1,
+            pattern.build().unwrap().captures_len()
+        );
+        assert_eq!(OneOrMore(Digit).group_count(), 0);
+        assert_eq!(Digit.group_count(), 0);
+        let regex = Regex::new(r"(a)(?P<b>b)(?:c)(?i)d").unwrap();
+        assert_eq!(regex.group_count(), 2);
+    }
+
+    #[test]
+    fn test_any_char_including_newline() {
+        assert_eq!(AnyChar.to_string(), Char.to_string());
+        let regex = create_reg_exp(
+            Exactly(Text("a".into()))
+                .and(Times(AnyChar, 1))
+                .and(Exactly(Text("b".into()))),
+        )
+        .unwrap();
+        assert!(!regex.is_match("a\nb"));
+        assert!(regex.is_match("a-b"));
+
+        let regex = create_reg_exp(
+            Exactly(Text("a".into()))
+                .and(OneOrMore(AnyCharIncludingNewline))
+                .and(Exactly(Text("b".into()))),
+        )
+        .unwrap();
+        assert_eq!(regex.as_str(), r"a[\s\S]+b");
+        assert!(regex.is_match("a\nb"));
+        assert!(regex.is_match("a-\r\nb"));
+        assert_eq!("[\\s\\S]".parse::<Type>().unwrap(), AnyCharIncludingNewline);
+    }
+
+    #[test]
+    fn test_boundary_before_and_after() {
+        let foo = Exactly(Text("foo".into()));
+        let regex = create_reg_exp(foo.clone().boundary_before()).unwrap();
+        assert_eq!(regex.as_str(), r"\b(?:foo)");
+        assert!(regex.is_match("foobar"));
+        assert!(!regex.is_match("barfoo"));
+
+        let regex = create_reg_exp(foo.boundary_after()).unwrap();
+        assert_eq!(regex.as_str(), r"(?:foo)\b");
+        assert!(regex.is_match("barfoo"));
+        assert!(!regex.is_match("foobar"));
+
+        // `Exactly` bounds both sides of a type.
+        let regex = create_reg_exp(Exactly(Digit)).unwrap();
+        assert!(!regex.is_match("1a"));
+        let regex = create_reg_exp(Times(Digit, 1).boundary_before()).unwrap();
+        assert!(regex.is_match("1a"));
+    }
+
+    #[test]
+    fn test_one_or_more_lazy() {
+        assert_eq!(one_or_more_lazy(Digit), LazilyOneOrMore(Digit));
+        let text = "<a><b>";
+        let greedy = create_reg_exp(
+            Exactly(Text("<".into()))
+                .and(OneOrMore(Char))
+                .and(Exactly(Text(">".into()))),
+        )
+        .unwrap();
+        let lazy = create_reg_exp(
+            Exactly(Text("<".into()))
+                .and(one_or_more_lazy(Char))
+                .and(Exactly(Text(">".into()))),
+        )
+        .unwrap();
+        assert_eq!(greedy.find(text).unwrap().as_str(), "<a><b>");
+        assert_eq!(lazy.find(text).unwrap().as_str(), "<a>");
+    }
+
+    #[test]
+    fn test_matches_fully() {
+        let date = Times(Digit, 4)
+            .and(Exactly(Text("-".into())))
+            .and(Times(Digit, 2))
+            .and(Exactly(Text("-".into())))
+            .and(Times(Digit, 2));
+        assert!(date.matches_fully("2014-01-01").unwrap());
+        assert!(!date.matches_fully("2014-01-01  ").unwrap());
+        assert!(!date.matches_fully("On 2014-01-01").unwrap());
+        assert!(Between(Digit, 4, 2).matches_fully("123").is_err());
+    }
+
+    #[test]
+    fn test_replace_all_with() {
+        let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+        assert_eq!(replace_all_with(&regex, "a1b22c333", "#"), "a#b#c#");
+        assert_eq!(replace_all_with(&regex, "abc", "#"), "abc");
+
+        let regex = create_reg_exp(
+            OneOrMore(Letter)
+                .grouped_as("name")
+                .and(Exactly(Text("=".into())))
+                .and(OneOrMore(Digit).grouped_as("value")),
+        )
+        .unwrap();
+        assert_eq!(
+            replace_all_with(&regex, "a=1, b=2", "$value=$name"),
+            "1=a, 2=b"
+        );
+        let replaced = replace_all_with_captures(&regex, "a=1, b=2", |captures| {
+            format!("{}={}", captures["name"].to_uppercase(), captures["value"])
+        });
+        assert_eq!(replaced, "A=1, B=2");
+    }
+
+    #[test]
+    fn test_line_start_and_end() {
+        let input = Exactly(LineStart)
+            .and(OneOrMore(Digit))
+            .and(Exactly(LineEnd));
+        assert_eq!(input.as_str(), r"^\d+$");
+        let regex = create_reg_exp(input.clone()).unwrap();
+        assert!(regex.is_match("123"));
+        assert!(!regex.is_match("123a"));
+        assert!(!regex.is_match("a\n123"));
+        let regex = create_reg_exp(input.multiline()).unwrap();
+        assert!(regex.is_match("a\n123"));
+
+        assert_eq!("^".parse::<Input>().unwrap(), Exactly(LineStart));
+        assert_eq!("$".parse::<Type>().unwrap(), LineEnd);
+        assert_eq!(Exactly(LineEnd).without_boundaries().as_str(), "$");
+    }
+
+    #[test]
+    fn test_repeat_range_lazy() {
+        let regex = create_reg_exp(Times(Letter, 1).repeat_range_lazy(2, 4)).unwrap();
+        assert_eq!(regex.as_str(), r"(?:[a-zA-Z]{1}){2,4}?");
+        assert_eq!(regex.find("abcdef").unwrap().as_str(), "ab");
+
+        // The whole statement is repeated, not only its last part.
+        let regex = create_reg_exp(Exactly(Text("ab".into())).repeat_range_lazy(2, 3)).unwrap();
+        assert_eq!(regex.find("abababab").unwrap().as_str(), "abab");
+
+        // A following statement can extend the repetition up to the maximum.
+        let regex = create_reg_exp(
+            Times(Letter, 1)
+                .repeat_range_lazy(2, 4)
+                .and(Exactly(Text("!".into()))),
+        )
+        .unwrap();
+        assert_eq!(regex.find("abcd!").unwrap().as_str(), "abcd!");
+        assert!(create_reg_exp(Times(Letter, 1).repeat_range_lazy(4, 2)).is_err());
+    }
+
+    #[test]
+    fn test_prefix_and_suffix() {
+        let input = OneOrMore(Digit).prefix("[").suffix("]");
+        assert_eq!(input.as_str(), r"\[\d+\]");
+        let regex = create_reg_exp(input.at_start().at_end()).unwrap();
+        assert!(regex.is_match("[123]"));
+        assert!(!regex.is_match("123"));
+        assert!(!regex.is_match("[123"));
+
+        let regex = create_reg_exp(OneOrMore(Letter).prefix("$(").suffix(")")).unwrap();
+        assert_eq!(regex.find("echo $(pwd)").unwrap().as_str(), "$(pwd)");
+    }
+
+    #[test]
+    fn test_positive_integer() {
+        let regex = create_reg_exp(positive_integer().at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A[1-9](?:\d+)?\z");
+        assert!(regex.is_match("10"));
+        assert!(regex.is_match("7"));
+        assert!(!regex.is_match("007"));
+        assert!(!regex.is_match("0"));
+        assert!(!regex.is_match("-7"));
+
+        assert_eq!(not(DigitNonZero), NotDigitNonZero);
+        let regex = create_reg_exp(Times(NotDigitNonZero, 1)).unwrap();
+        assert!(regex.is_match("0"));
+        assert!(!regex.is_match("5"));
+    }
+
+    #[test]
+    fn test_followed_by() {
+        let year = Times(Digit, 4).try_grouped().unwrap();
+        let month = Exactly(Text("-".into()))
+            .and(Times(Digit, 2).grouped())
+            .build()
+            .unwrap();
+        let regex = create_reg_exp(year.followed_by(month)).unwrap();
+        assert_eq!(regex.as_str(), r"(?:(\d{4}))(?:-(\d{2}))");
+        let captures = regex.captures("2014-01").unwrap();
+        assert_eq!(&captures[1], "2014");
+        assert_eq!(&captures[2], "01");
+
+        // An alternation of a compiled regex stays on its side.
+        let either = Regex::new("a|b").unwrap();
+        let regex = create_reg_exp(either.clone().followed_by(Regex::new("c").unwrap())).unwrap();
+        assert!(!regex.is_match("a"));
+        assert!(regex.is_match("ac"));
+        let regex = create_reg_exp(either.clone().and(Regex::new("c").unwrap())).unwrap();
+        assert!(regex.is_match("ac"));
+        assert!(!regex.is_match("a"));
+        let regex = create_reg_exp(either + Exactly(Text("c".into()))).unwrap();
+        assert!(regex.is_match("bc"));
+        assert!(!regex.is_match("b"));
+    }
+
+    #[test]
+    fn test_ascii_only() {
+        let arabic_three = "\u{0663}";
+        let regex = create_reg_exp(OneOrMore(Digit).at_start().at_end()).unwrap();
+        assert!(regex.is_match(arabic_three));
+        let regex = create_reg_exp(OneOrMore(Digit).ascii_only().at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A(?-u:\d+)\z");
+        assert!(!regex.is_match(arabic_three));
+        assert!(regex.is_match("3"));
+
+        let regex = create_reg_exp(OneOrMore(WordChar).ascii_only().at_start().at_end()).unwrap();
+        assert!(regex.is_match("abc_1"));
+        assert!(!regex.is_match("é"));
+
+        assert!(create_reg_exp(OneOrMore(UnicodeLetter).ascii_only()).is_err());
+        assert!(create_reg_exp(OneOrMore(Char).ascii_only()).is_err());
+    }
+
+    #[test]
+    fn test_punctuation() {
+        let regex = create_reg_exp(Times(Punctuation, 1).at_start().at_end()).unwrap();
+        for c in ["!", ",", ".", "-", "["] {
+            assert!(regex.is_match(c), "{}", c);
+        }
+        for c in ["a", "1", " ", "_a"] {
+            assert!(!regex.is_match(c), "{}", c);
+        }
+
+        assert_eq!(not(Punctuation), NotPunctuation);
+        let regex = create_reg_exp(OneOrMore(NotPunctuation).at_start().at_end()).unwrap();
+        assert!(regex.is_match("abc 1"));
+        assert!(!regex.is_match("abc!"));
+    }
+
+    #[test]
+    fn test_posix() {
+        let cases = [
+            (PosixClass::Alnum, "a", "-"),
+            (PosixClass::Alpha, "Z", "1"),
+            (PosixClass::Ascii, "~", "é"),
+            (PosixClass::Blank, "\t", "\n"),
+            (PosixClass::Cntrl, "\x07", "a"),
+            (PosixClass::Digit, "7", "a"),
+            (PosixClass::Graph, "!", " "),
+            (PosixClass::Lower, "a", "A"),
+            (PosixClass::Print, " ", "\t"),
+            (PosixClass::Punct, ",", "a"),
+            (PosixClass::Space, "\n", "a"),
+            (PosixClass::Upper, "A", "a"),
+            (PosixClass::Word, "_", "-"),
+            (PosixClass::Xdigit, "f", "g"),
+        ];
+        assert_eq!(cases.len(), PosixClass::all().len());
+        for (class, matching, other) in cases {
+            let regex = create_reg_exp(Times(Posix(class), 1).at_start().at_end()).unwrap();
+            assert!(regex.is_match(matching), "{}", class);
+            assert!(!regex.is_match(other), "{}", class);
+            let regex = create_reg_exp(Times(not(Posix(class)), 1).at_start().at_end()).unwrap();
+            assert!(!regex.is_match(matching), "{}", class);
+            assert!(regex.is_match(other), "{}", class);
+        }
+    }
+
+    #[test]
+    fn test_try_chaining() {
+        let regex = Times(Digit, 2).try_and(Exactly(Text("-".into()))).unwrap();
+        assert_eq!(regex.as_str(), r"\d{2}-");
+        let regex = Exactly(Text("a".into()))
+            .try_or(Exactly(Text("b".into())))
+            .unwrap();
+        assert!(regex.is_match("b"));
+        let regex = Exactly(Text("a".into())).try_optionally().unwrap();
+        assert_eq!(regex.as_str(), "(?:a)?");
+
+        assert!(matches!(
+            Times(Digit, 2).try_and(Between(Digit, 4, 2)),
+            Err(Error::InvalidRange(4, 2))
+        ));
+        assert!(matches!(
+            Between(Digit, 4, 2).try_or(Digit),
+            Err(Error::InvalidRange(4, 2))
+        ));
+        assert!(matches!(
+            Between(Digit, 4, 2).try_optionally(),
+            Err(Error::InvalidRange(4, 2))
+        ));
+    }
+
+    #[test]
+    fn test_pattern_keeps_first_error() {
+        let pattern = Between(Digit, 4, 2).and(Digit);
+        assert!(matches!(pattern.build(), Err(Error::InvalidRange(4, 2))));
+        let pattern = Times(Digit, 2)
+            .and(Between(Letter, 3, 1))
+            .grouped_as("broken")
+            .and(CharInRange('z', 'a'));
+        assert!(matches!(pattern.build(), Err(Error::InvalidRange(3, 1))));
+        assert!(matches!(
+            create_reg_exp(Pattern::new().push_input(Exactly(CharInRange('z', 'a')))),
+            Err(Error::InvalidCharRange('z', 'a'))
+        ));
+        // Combinators, which build the regex string themselves, keep the error as well.
+        assert!(matches!(
+            Between(Digit, 4, 2).times(2).build(),
+            Err(Error::InvalidRange(4, 2))
+        ));
+        assert!(matches!(
+            OneOrMore(Digit)
+                .repeated_with_separator(Between(Digit, 4, 2), 1)
+                .build(),
+            Err(Error::InvalidRange(4, 2))
+        ));
+        assert!(matches!(
+            one_of(vec![Times(Digit, 1), Between(Digit, 4, 2)]).build(),
+            Err(Error::InvalidRange(4, 2))
+        ));
+        assert!(matches!(
+            Exactly(CharInRange('z', 'a')).without_boundaries().build(),
+            Err(Error::InvalidCharRange('z', 'a'))
+        ));
+    }
+
+    #[test]
+    fn test_grouped_as_in_chain() {
+        let dash = || Exactly(Text("-".into()));
+        let date = Times(Digit, 4)
+            .grouped_as("year")
+            .and(dash())
+            .and(Times(Digit, 2).grouped_as("month"))
+            .and(dash())
+            .and(Times(Digit, 2).grouped_as("day"))
+            .grouped_as("date")
+            .or(Exactly(Text("today".into())).grouped());
+        let regex = create_reg_exp(date).unwrap();
+        let captures = regex.captures("On 2014-10-14, bar happened.").unwrap();
+        assert_eq!(&captures["date"], "2014-10-14");
+        assert_eq!(&captures["year"], "2014");
+        assert_eq!(&captures["month"], "10");
+        assert_eq!(&captures["day"], "14");
+        assert_eq!(&regex.captures("today").unwrap()[5], "today");
+    }
+
+    #[test]
+    fn test_quantified_text_is_grouped() {
+        let input = Times(Text("ab".into()), 2);
+        assert_eq!(input.to_string(), "(?:ab){2}");
+        let regex = create_reg_exp(input.at_start().at_end()).unwrap();
+        assert!(regex.is_match("abab"));
+        assert!(!regex.is_match("abb"));
+
+        let regex = create_reg_exp(OneOrMore(Text("ab".into())).at_start().at_end()).unwrap();
+        assert!(regex.is_match("ababab"));
+        assert!(!regex.is_match("abbb"));
+
+        // A single character does not need a group.
+        assert_eq!(Times(Text("a".into()), 2).to_string(), "a{2}");
+        assert_eq!(Maybe(Words).to_string(), r"(?:\b\w+(?:\s+\w+)*\b)?");
+    }
+
+    #[test]
+    fn test_match_repetition_count() {
+        let regex = create_reg_exp(OneOrMore(Digit)).unwrap();
+        assert_eq!(match_repetition_count(&regex, "a1b2c3"), Some(3));
+        assert_eq!(match_repetition_count(&regex, "123"), Some(3));
+        assert_eq!(match_repetition_count(&regex, "abc"), None);
+        assert!(match_repetition_count(&regex, "pa55w0rd").unwrap() >= 3);
+
+        // Multi-byte characters are counted once.
+        let regex = create_reg_exp(OneOrMore(UnicodeLetter)).unwrap();
+        assert_eq!(match_repetition_count(&regex, "ä1ö"), Some(2));
+
+        // An empty match is still a match.
+        let regex = create_reg_exp(Maybe(Digit).at_start()).unwrap();
+        assert_eq!(match_repetition_count(&regex, "a"), Some(0));
+    }
+
+    #[test]
+    fn test_newline_any() {
+        let regex = create_reg_exp(Times(NewlineAny, 1).at_start().at_end()).unwrap();
+        assert_eq!(regex.as_str(), r"\A(?:\r\n|\r|\n){1}\z");
+        assert!(regex.is_match("\r\n"));
+        assert!(regex.is_match("\r"));
+        assert!(regex.is_match("\n"));
+        assert!(!regex.is_match(" "));
+        assert!(!regex.is_match("\n\r"));
+
+        let regex = create_reg_exp(
+            OneOrMore(Letter)
+                .grouped()
+                .and(Times(NewlineAny, 1))
+                .and(OneOrMore(Letter)),
+        )
+        .unwrap();
+        assert_eq!(regex.find("ab\r\ncd").unwrap().as_str(), "ab\r\ncd");
+        assert_eq!(not(NewlineAny), NewlineAny);
+    }
+
+    #[test]
+    fn test_then() {
+        let px = || Exactly(Text("px".into()));
+        assert_eq!(OneOrMore(Digit).then(px()), OneOrMore(Digit).and(px()));
+        let either = Exactly(Text("a".into())).or(Exactly(Text("b".into())));
+        assert_eq!(either.clone().then(px()), either.and(px()));
+        let regex = create_reg_exp(OneOrMore(Digit).then(px())).unwrap();
+        assert_eq!(regex.find("width: 12px").unwrap().as_str(), "12px");
+    }
+
+    #[test]
+    fn test_named_capture_or() {
+        let dot = || Exactly(Text(".".into()));
+        let version = OneOrMore(Digit)
+            .grouped_as("major")
+            .and(dot())
+            .and(OneOrMore(Digit).grouped_as("minor"))
+            .and(dot().and(OneOrMore(Digit).grouped_as("patch")).optionally());
+        let regex = create_reg_exp(version.at_start().at_end()).unwrap();
+        assert_eq!(named_capture_or(&regex, "1.2.3", "patch", "0"), Some("3"));
+        assert_eq!(named_capture_or(&regex, "1.2", "patch", "0"), Some("0"));
+        assert_eq!(named_capture_or(&regex, "1.2", "minor", "0"), Some("2"));
+        assert_eq!(named_capture_or(&regex, "1", "patch", "0"), None);
+        // An unknown group also returns the default.
+        assert_eq!(
+            named_capture_or(&regex, "1.2", "build", "none"),
+            Some("none")
+        );
+    }
+
+    #[test]
+    fn test_space_only() {
+        let regex = create_reg_exp(OneOrMore(SpaceOnly).at_start().at_end()).unwrap();
+        assert!(regex.is_match(" "));
+        assert!(regex.is_match("   "));
+        assert!(!regex.is_match("\t"));
+        assert!(!regex.is_match(" \n"));
+
+        assert_eq!(not(SpaceOnly), NotSpaceOnly);
+        let regex = create_reg_exp(OneOrMore(NotSpaceOnly).at_start().at_end()).unwrap();
+        assert!(regex.is_match("a\tb"));
+        assert!(!regex.is_match("a b"));
+
+        // The space survives the extended mode.
+        let flags = Flags {
+            ignore_whitespace: true,
+            ..Flags::default()
+        };
+        let regex = create_reg_exp(Times(SpaceOnly, 1).with_flags(flags).unwrap()).unwrap();
+        assert!(regex.is_match(" "));
+        assert!(!regex.is_match("a"));
+    }
+
+    #[test]
+    fn test_repeat_text_ci() {
+        let regex = create_reg_exp(
+            repeat_text_ci("ab", 2)
+                .and(Exactly(Text("x".into())))
+                .at_start()
+                .at_end(),
+        )
+        .unwrap();
+        assert!(regex.is_match("ABabx"));
+        assert!(regex.is_match("aBAbx"));
+        assert!(!regex.is_match("ABabX"));
+        assert!(!regex.is_match("ABx"));
+        let regex = create_reg_exp(repeat_text_ci("a.", 2).at_start().at_end()).unwrap();
+        assert!(regex.is_match("A.a."));
+        assert!(!regex.is_match("AbAb"));
+    }
+
+    #[test]
+    fn test_create_reg_exp_with() {
+        let input = || Exactly(Text("abc".into())).and(OneOrMore(Digit));
+        let regex = create_reg_exp_with(input(), |builder| builder.case_insensitive(true)).unwrap();
+        assert_eq!(regex.as_str(), create_reg_exp(input()).unwrap().as_str());
+        assert!(regex.is_match("ABC12"));
+        assert!(!create_reg_exp(input()).unwrap().is_match("ABC12"));
+
+        let huge = Times(UnicodeLetter, 1000);
+        assert!(matches!(
+            create_reg_exp_with(huge, |builder| builder.size_limit(100)),
+            Err(Error::RegexError(_))
+        ));
+        assert!(matches!(
+            create_reg_exp_with(Between(Digit, 4, 2), |builder| builder),
+            Err(Error::InvalidRange(4, 2))
+        ));
+    }
+
+    #[test]
+    fn test_create_reg_exp_with_raised_size_limit() {
+        let huge = || Times(UnicodeLetter, 300);
+        assert!(create_reg_exp(huge()).is_err());
+        let regex = create_reg_exp_with(huge(), |builder| builder.size_limit(1 << 30)).unwrap();
+        assert!(regex.is_match(&"ä".repeat(300)));
+    }
 }